@@ -0,0 +1,54 @@
+//! Compare the dense grid and tier scan lookups
+//!
+//!     cargo bench --bench lookup
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use flinn_engdahl::{Backend, Lookup};
+
+/// Locations on a 0.1 degree grid over the globe
+fn locations() -> Vec<(f64, f64)> {
+    let mut out = vec![];
+    for i in 0 ..= 1800 {
+        for j in (0 .. 3600).step_by(7) {
+            out.push((-90.0 + i as f64 * 0.1, -180.0 + j as f64 * 0.1));
+        }
+    }
+    out
+}
+
+fn time(lookup: Lookup, locs: &[(f64, f64)], rounds: usize) -> Duration {
+    let start = Instant::now();
+    for _ in 0 .. rounds {
+        for &(lat, lon) in locs {
+            black_box(lookup.region_number(black_box(lat), black_box(lon)).unwrap());
+        }
+    }
+    start.elapsed()
+}
+
+fn time_batch(locs: &[(f64, f64)], rounds: usize) -> Duration {
+    let lats : Vec<_> = locs.iter().map(|p| p.0).collect();
+    let lons : Vec<_> = locs.iter().map(|p| p.1).collect();
+    let mut out = vec![0u16; locs.len()];
+    let start = Instant::now();
+    for _ in 0 .. rounds {
+        black_box(flinn_engdahl::region_numbers(black_box(&lats), black_box(&lons), &mut out));
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let locs = locations();
+    let rounds = 5;
+    let n = (locs.len() * rounds) as f64;
+    let scan = time(Lookup::new().backend(Backend::TierScan), &locs, rounds);
+    let dense = time(Lookup::new().backend(Backend::DenseGrid), &locs, rounds);
+    let batch = time_batch(&locs, rounds);
+    println!("lookups:    {}", n);
+    println!("tier scan:  {:8.1} ns/lookup", scan.as_nanos() as f64 / n);
+    println!("dense grid: {:8.1} ns/lookup", dense.as_nanos() as f64 / n);
+    println!("batch:      {:8.1} ns/lookup", batch.as_nanos() as f64 / n);
+    println!("speed-up:   {:8.1}x", scan.as_secs_f64() / dense.as_secs_f64());
+}
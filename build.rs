@@ -0,0 +1,106 @@
+//! Generate the embedded tables from the data files
+//!
+//! The files in `data/` are the single source of truth; each is read,
+//! validated and written as Rust source into `OUT_DIR`, where `src/` picks
+//! it up with `include!`.
+
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/data.rs"]
+mod data;
+
+use data::DataError;
+
+/// Read a data file, failing the build if it is missing
+fn read(file: &str) -> String {
+    let path = Path::new("data").join(file);
+    println!("cargo:rerun-if-changed={}", path.display());
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("reading {}: {}", path.display(), err))
+}
+
+/// Write generated source to `OUT_DIR`
+fn write(file: &str, out: String) {
+    let dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&dir).join(file), out).unwrap();
+}
+
+fn names_write(name: &str, names: &[String]) -> String {
+    let mut out = String::new();
+    out += &format!("const {}: [&str; {}] = [\n", name, names.len());
+    for n in names {
+        out += &format!(" {:?},\n", n);
+    }
+    out += "];\n";
+    out
+}
+
+fn lat_lon_index_write(onsets: &[u16]) -> String {
+    let mut out = String::new();
+    // Onset of each tier (0-based), followed by the total number of segments
+    out += &format!("static TIER_ONSETS: [u16; {}] = [\n", onsets.len());
+    for a in onsets {
+        out += &format!(" {},\n", a);
+    }
+    out += "];\n";
+    out
+}
+
+fn latitude_tiers_write(lons: &[u8], regions: &[u32]) -> String {
+    let mut out = String::new();
+    out += &format!("static TIER_LONS: [u8; {}] = [\n", lons.len());
+    for a in lons {
+        out += &format!(" {},\n", a);
+    }
+    out += "];\n";
+    out += &format!("static TIER_REGIONS: [u32; {}] = [\n", regions.len());
+    for w in regions {
+        out += &format!(" 0x{:08x},\n", w);
+    }
+    out += "];\n";
+    out
+}
+
+fn seismic_regions_write(seis: &[usize]) -> String {
+    let mut out = String::new();
+    out += &format!("const SEISMIC_REGIONS: [usize; {}] = [\n", seis.len());
+    for s in seis {
+        out += &format!(" {},\n", s);
+    }
+    out += "];\n";
+    out
+}
+
+fn generate() -> Result<(), DataError> {
+    println!("cargo:rerun-if-changed=src/data.rs");
+
+    let names = data::parse_names("names.asc", &read("names.asc"))?;
+    let regions = names.len();
+    let expanded = data::parse_names("names_expanded.asc", &read("names_expanded.asc"))?;
+    data::check_count("names_expanded.asc", &expanded, regions)?;
+    let seis_names = data::parse_names("seisnames.asc", &read("seisnames.asc"))?;
+    let seis = data::parse_seismic_regions("seisreg.asc", &read("seisreg.asc"), regions, seis_names.len())?;
+
+    let llindx = data::parse_quadsidx("quadsidx.asc", &read("quadsidx.asc"))?;
+    let mut sects = vec![];
+    for file in data::SECTS.iter() {
+        sects.push((*file, data::parse_sect(file, &read(file))?));
+    }
+    let lattiers = data::latitude_tiers(&llindx, &sects, regions)?;
+    let tiers = data::compact(&llindx, &lattiers)?;
+
+    write("names.rs", names_write("NAMES", &names));
+    write("names_expanded.rs", names_write("NAMES_EXPANDED", &expanded));
+    write("seismic_names.rs", names_write("SEISMIC_NAMES", &seis_names));
+    write("seismic_regions.rs", seismic_regions_write(&seis));
+    write("lat_lon_index.rs", lat_lon_index_write(&tiers.onsets));
+    write("latitude_tiers.rs", latitude_tiers_write(&tiers.lons, &tiers.regions));
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = generate() {
+        panic!("invalid data file {}", err);
+    }
+}
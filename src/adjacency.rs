@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+
+use crate::{RegionError, Regionalization};
+
+/// Border between two geographic regions
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Border {
+    /// Lower region number
+    pub a: usize,
+    /// Higher region number
+    pub b: usize,
+    /// Length of the shared boundary in degrees, 0 if the regions only meet at a corner
+    ///
+    /// Edges along a meridian count 1 degree of latitude, edges along a
+    /// parallel 1 degree of longitude
+    pub length: f64,
+}
+
+/// Neighbor of a geographic region
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Neighbor {
+    /// Region number of the neighbor
+    pub region: usize,
+    /// Length of the shared boundary in degrees, 0 if the regions only meet at a corner
+    pub length: f64,
+}
+
+impl Regionalization {
+    /// Borders between all pairs of adjacent regions, see [`borders`](crate::borders)
+    ///
+    /// Cells are adjacent across the antimeridian and all cells touching a
+    /// pole meet at the pole.
+    pub fn borders(&self) -> Vec<Border> {
+        let grid = self.cell_grid();
+        let mut pairs : BTreeMap<(usize, usize), f64> = BTreeMap::new();
+        let mut touch = |a: usize, b: usize, length: f64| {
+            if a != b {
+                *pairs.entry((a.min(b), a.max(b))).or_insert(0.0) += length;
+            }
+        };
+        let wrap = |lon: i16| if lon >= 180 { lon - 360 } else { lon };
+        for lat in -90 .. 90 {
+            for lon in -180 .. 180 {
+                let r = grid.get(lat, lon).unwrap();
+                // East, wrapping across the antimeridian
+                touch(r, grid.get(lat, wrap(lon + 1)).unwrap(), 1.0);
+                if let Some(n) = grid.get(lat + 1, lon) {
+                    touch(r, n, 1.0);
+                    touch(r, grid.get(lat + 1, wrap(lon + 1)).unwrap(), 0.0);
+                    touch(grid.get(lat, wrap(lon + 1)).unwrap(), n, 0.0);
+                }
+            }
+        }
+        // Every cell along a pole touches every other at the pole
+        for &lat in [-90, 89].iter() {
+            let mut at_pole : Vec<usize> = (-180 .. 180).map(|lon| grid.get(lat, lon).unwrap()).collect();
+            at_pole.sort_unstable();
+            at_pole.dedup();
+            for (i, &a) in at_pole.iter().enumerate() {
+                for &b in &at_pole[i+1..] {
+                    touch(a, b, 0.0);
+                }
+            }
+        }
+        pairs.into_iter().map(|((a, b), length)| Border { a, b, length }).collect()
+    }
+    /// Regions adjacent to geographic `region`, see [`neighbors`](crate::neighbors)
+    ///
+    /// Only the cells of `region` and those around them are visited
+    pub fn neighbors(&self, region: usize) -> Result<Vec<Neighbor>, RegionError> {
+        if self.name(region).is_none() {
+            return Err(RegionError::BadRegionNumber);
+        }
+        let grid = self.cell_grid();
+        let mut near : BTreeMap<usize, f64> = BTreeMap::new();
+        let mut touch = |n: Option<usize>, length: f64| {
+            match n {
+                Some(n) if n != region => *near.entry(n).or_insert(0.0) += length,
+                _ => {},
+            }
+        };
+        let wrap = |lon: i16| (lon + 180).rem_euclid(360) - 180;
+        let mut at_pole = [false, false];
+        for cell in grid.cells(region) {
+            let (lat, lon) = (cell.lat, cell.lon);
+            // Edges east and west, wrapping across the antimeridian, north and south
+            touch(grid.get(lat, wrap(lon + 1)), 1.0);
+            touch(grid.get(lat, wrap(lon - 1)), 1.0);
+            touch(grid.get(lat + 1, lon), 1.0);
+            touch(grid.get(lat - 1, lon), 1.0);
+            // Corners
+            for &(dlat, dlon) in [(1, 1), (1, -1), (-1, 1), (-1, -1)].iter() {
+                touch(grid.get(lat + dlat, wrap(lon + dlon)), 0.0);
+            }
+            at_pole[0] |= lat == -90;
+            at_pole[1] |= lat == 89;
+        }
+        // Every cell along a pole touches every other at the pole
+        for (&lat, _) in [-90, 89].iter().zip(at_pole.iter()).filter(|(_, &p)| p) {
+            for lon in -180 .. 180 {
+                touch(grid.get(lat, lon), 0.0);
+            }
+        }
+        Ok(near.into_iter().map(|(region, length)| Neighbor { region, length }).collect())
+    }
+}
+
+/// Regions adjacent to a geographic region
+///
+/// Regions are adjacent if their 1 x 1 degree cells share an edge or a corner.
+/// Neighbors are returned in region number order.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let near = fe::neighbors(476).unwrap(); // SOUTHERN NEW ENGLAND
+///  assert!(near.iter().any(|n| n.region == 472)); // NEW YORK
+/// ```
+///
+/// # Arguments
+///   - region - Geographic Region Number [1, 757]
+///
+pub fn neighbors(region: usize) -> Result<Vec<Neighbor>, RegionError> {
+    Regionalization::fe1995().neighbors(region)
+}
+
+/// Iterate over the full region adjacency graph
+///
+/// Each pair of adjacent regions is returned once, ordered by (`a`, `b`) with `a` < `b`
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let n = fe::borders().filter(|b| b.length > 0.0).count();
+///  assert!(n > 757);
+/// ```
+///
+pub fn borders() -> impl Iterator<Item = Border> {
+    Regionalization::fe1995().borders().into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn symmetric() {
+        let borders : Vec<_> = crate::borders().collect();
+        for b in &borders {
+            assert!(b.a < b.b);
+            assert!(b.length >= 0.0);
+        }
+        let a = crate::neighbors(162).unwrap();
+        for n in &a {
+            let back = crate::neighbors(n.region).unwrap();
+            assert!(back.iter().any(|m| m.region == 162 && m.length == n.length));
+        }
+    }
+    #[test]
+    fn neighbors_match_borders() {
+        let fe = crate::Regionalization::fe1995();
+        let borders = fe.borders();
+        for region in 1 ..= fe.number_of_regions() {
+            let expected : Vec<_> = borders.iter()
+                .filter_map(|b| {
+                    if b.a == region {
+                        Some(crate::Neighbor { region: b.b, length: b.length })
+                    } else if b.b == region {
+                        Some(crate::Neighbor { region: b.a, length: b.length })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            assert_eq!(fe.neighbors(region).unwrap(), expected, "{}", region);
+        }
+    }
+    #[test]
+    fn antimeridian() {
+        // Fiji Islands borders Fiji Islands Region across longitude 180
+        let n = crate::neighbors(crate::region_number(-15.5, 179.5).unwrap()).unwrap();
+        let west = crate::region_number(-15.5, -179.5).unwrap();
+        assert!(n.iter().any(|m| m.region == west));
+    }
+    #[test]
+    fn bad_region() {
+        assert_eq!(crate::neighbors(0), Err(crate::RegionError::BadRegionNumber));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Lookup, RegionError};
+#[cfg(all(feature = "std", feature = "dense-grid"))]
+use crate::dense_grid::grid_index;
+
+/// Minimum batch length split across threads
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN: usize = 1 << 16;
+
+impl Lookup {
+    /// Get region numbers for slices of locations,
+    /// see [`region_numbers`](crate::region_numbers)
+    ///
+    /// # Panics
+    ///   - If `lats`, `lons` and `out` differ in length
+    #[cfg(feature = "std")]
+    pub fn region_numbers(&self, lats: &[f64], lons: &[f64], out: &mut [u16]) -> Vec<(usize, RegionError)> {
+        assert_eq!(lats.len(), lons.len(), "latitudes and longitudes differ in length");
+        assert_eq!(lats.len(), out.len(), "locations and output differ in length");
+        #[cfg(feature = "parallel")]
+        {
+            if lats.len() >= PARALLEL_MIN {
+                return self.region_numbers_parallel(lats, lons, out);
+            }
+        }
+        self.region_numbers_serial(lats, lons, out, 0)
+    }
+
+    #[cfg(feature = "std")]
+    fn region_numbers_serial(&self, lats: &[f64], lons: &[f64], out: &mut [u16], offset: usize)
+                             -> Vec<(usize, RegionError)> {
+        #[cfg(feature = "dense-grid")]
+        let grid = self.grid();
+        let mut errors = vec![];
+        for (i, ((&lat, &lon), n)) in lats.iter().zip(lons).zip(out.iter_mut()).enumerate() {
+            // Locations already in range go straight to the grid, anything
+            // else (errors, wrapping, the antimeridian) takes the full path
+            #[cfg(feature = "dense-grid")]
+            let v = match grid {
+                Some((grid, quadid)) if lat.abs() <= 90.0 && lon.abs() < 180.0 =>
+                    Ok(grid[grid_index(lat, lon, &quadid)] as usize),
+                _ => self.region_number(lat, lon),
+            };
+            #[cfg(not(feature = "dense-grid"))]
+            let v = self.region_number(lat, lon);
+            match v {
+                Ok(v) => *n = v as u16,
+                Err(err) => {
+                    *n = 0;
+                    errors.push((offset + i, err));
+                },
+            }
+        }
+        errors
+    }
+
+    #[cfg(feature = "parallel")]
+    fn region_numbers_parallel(&self, lats: &[f64], lons: &[f64], out: &mut [u16]) -> Vec<(usize, RegionError)> {
+        let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        let chunk = lats.len().div_ceil(threads);
+        std::thread::scope(|s| {
+            let handles : Vec<_> = lats.chunks(chunk).zip(lons.chunks(chunk)).zip(out.chunks_mut(chunk))
+                .enumerate()
+                .map(|(k, ((lats, lons), out))| {
+                    s.spawn(move || self.region_numbers_serial(lats, lons, out, k * chunk))
+                })
+                .collect();
+            handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+        })
+    }
+
+    /// Get region numbers for an iterator of (lat, lon) locations,
+    /// see [`region_numbers_iter`](crate::region_numbers_iter)
+    pub fn region_numbers_iter<I>(&self, locations: I) -> RegionNumbers<I::IntoIter>
+        where I: IntoIterator<Item = (f64, f64)> {
+        RegionNumbers { lookup: *self, locations: locations.into_iter() }
+    }
+}
+
+/// Iterator over region numbers of locations, see [`region_numbers_iter`]
+#[derive(Debug,Clone)]
+pub struct RegionNumbers<I> {
+    lookup: Lookup,
+    locations: I,
+}
+
+impl<I: Iterator<Item = (f64, f64)>> Iterator for RegionNumbers<I> {
+    type Item = Result<usize, RegionError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (lat, lon) = self.locations.next()?;
+        Some(self.lookup.region_number(lat, lon))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.locations.size_hint()
+    }
+}
+
+/// Get the Flinn_Engdahl region numbers for slices of latitudes and longitudes
+///
+/// Region numbers are written to `out`.  Locations that cannot be looked up
+/// are set to 0 and reported with their index, without stopping the batch.
+/// With the `parallel` feature, large batches are split across threads.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let lats = [-77.845753, 41.440971, 91.0];
+///  let lons = [166.675927, -71.502289, 0.0];
+///  let mut out = [0u16; 3];
+///  let errors = fe::region_numbers(&lats, &lons, &mut out);
+///  assert_eq!(out, [727, 476, 0]);
+///  assert_eq!(errors, [(2, fe::RegionError::BadLatitude)]);
+/// ```
+///
+/// # Arguments
+///   - lats - Latitudes  [-90, 90]
+///   - lons - Longitudes [-360, 360]
+///   - out  - Region numbers, same length as `lats` and `lons`
+///
+/// # Returns
+///   - Index and error of each location that could not be looked up
+///
+/// # Panics
+///   - If `lats`, `lons` and `out` differ in length
+///
+#[cfg(feature = "std")]
+pub fn region_numbers(lats: &[f64], lons: &[f64], out: &mut [u16]) -> Vec<(usize, RegionError)> {
+    Lookup::new().region_numbers(lats, lons, out)
+}
+
+/// Get the Flinn_Engdahl region numbers for an iterator of (lat, lon) locations
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let locs = vec![(-77.845753, 166.675927), (0.0, 400.0)];
+///  let n : Vec<_> = fe::region_numbers_iter(locs).collect();
+///  assert_eq!(n, [Ok(727), Err(fe::RegionError::BadLongitude)]);
+/// ```
+///
+/// # Arguments
+///   - locations - (Latitude, Longitude) pairs
+///
+/// # Returns
+///   - Iterator over the region number or error of each location
+///
+pub fn region_numbers_iter<I>(locations: I) -> RegionNumbers<I::IntoIter>
+    where I: IntoIterator<Item = (f64, f64)> {
+    Lookup::new().region_numbers_iter(locations)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Backend, LongitudePolicy};
+
+    fn locations() -> (Vec<f64>, Vec<f64>) {
+        let mut lats = vec![];
+        let mut lons = vec![];
+        for i in 0 ..= 360 {
+            for j in 0 ..= 760 {
+                lats.push(-90.25 + i as f64 * 0.5);
+                lons.push(-380.0 + j as f64);
+            }
+        }
+        lats[17] = f64::NAN;
+        (lats, lons)
+    }
+
+    #[test]
+    fn matches_single_lookups() {
+        let (lats, lons) = locations();
+        for &fe in &[Lookup::new(),
+                     Lookup::new().backend(Backend::TierScan),
+                     Lookup::new().longitude(LongitudePolicy::Wrap)] {
+            let mut out = vec![0; lats.len()];
+            let errors = fe.region_numbers(&lats, &lons, &mut out);
+            let mut k = 0;
+            for (i, n) in fe.region_numbers_iter(lats.iter().copied().zip(lons.iter().copied())).enumerate() {
+                match n {
+                    Ok(n) => assert_eq!(out[i] as usize, n),
+                    Err(err) => {
+                        assert_eq!(out[i], 0);
+                        assert_eq!(errors[k], (i, err));
+                        k += 1;
+                    },
+                }
+            }
+            assert_eq!(k, errors.len());
+            assert!(!errors.is_empty());
+        }
+    }
+    #[test]
+    #[should_panic]
+    fn length_mismatch() {
+        region_numbers(&[0.0], &[0.0, 1.0], &mut [0; 2]);
+    }
+}
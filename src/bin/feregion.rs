@@ -0,0 +1,402 @@
+//! Flinn-Engdahl region of a location, named after the USGS `feregion` program
+//!
+//!     feregion [-n] <lat> <lon>
+//!     feregion [-n] < locations.txt
+//!     feregion annotate [options] [catalog.csv]
+//!     feregion sac [--verify] <file.sac>...
+//!     feregion origin [--dry-run] [--overwrite] [db.origin]
+//!
+//! Each location is printed on a line of its own as the geographic region
+//! name alone, as `feregion.pl` from the USGS 1995 distribution does; with
+//! `-n` the region number comes first.  Locations on stdin are latitude and longitude pairs
+//! separated by whitespace or commas, one per line; blank lines and lines
+//! starting with `#` are skipped.
+//!
+//! `annotate` copies a CSV catalog to stdout with region columns appended,
+//! see [`CsvAnnotator`](flinn_engdahl::CsvAnnotator).
+//!
+//! `sac` sets the event region, `ievreg`, of SAC files from their event
+//! location, or with `--verify` lists the files where it differs.
+//!
+//! `origin` copies a CSS3.0 `origin` table to stdout with the `grn` and
+//! `srn` columns filled, see [`OriginAnnotator`](flinn_engdahl::OriginAnnotator).
+
+use std::io::{self, BufRead, Write};
+
+use flinn_engdahl as fe;
+
+const USAGE: &str = "\
+usage: feregion [-n] <lat> <lon>
+       feregion [-n] < locations
+       feregion annotate [options] [catalog.csv]
+       feregion sac [--verify] <file.sac>...
+       feregion origin [--dry-run] [--overwrite] [db.origin]
+
+Print the Flinn-Engdahl geographic region name of a location.
+Without arguments, read latitude and longitude pairs from stdin, separated
+by whitespace or commas, one per line.
+
+options:
+  -n, --number   Print the region number before the name
+  -h, --help     Show this message
+
+annotate copies a CSV catalog, from a file or stdin, to stdout with the
+region number, name and seismic region number appended to each record.
+Records without a valid location are reported and left unannotated.
+
+annotate options:
+  --lat <column>        Latitude column name or number, from 1
+                        [default: latitude or lat]
+  --lon <column>        Longitude column name or number, from 1
+                        [default: longitude, lon, long or lng]
+  --delimiter <char>    Field delimiter [default: ,]
+  --no-header           The first record is data; columns must be numbers
+
+sac sets the event region, ievreg, in the header of each SAC file from its
+event location, evla and evlo, printing the files changed.
+
+sac options:
+  --verify              Change nothing; print the files where ievreg differs
+
+origin copies a CSS3.0 origin table, from a file or stdin, to stdout with
+null (-1) geographic region numbers, grn, filled from lat and lon and null
+seismic region numbers, srn, from grn.  Records that cannot be filled are
+reported and left unchanged.
+
+origin options:
+  --dry-run             Print a diff of the records that would change
+  --overwrite           Also replace non-null grn and srn that differ
+";
+
+/// Exit status: a location could not be looked up
+const FAILURE: i32 = 1;
+/// Exit status: bad command line
+const USAGE_ERROR: i32 = 2;
+
+/// Parse a number, naming it in the error
+fn number(what: &str, s: &str) -> Result<f64, String> {
+    s.trim().parse().map_err(|_| format!("invalid {} {:?}", what, s.trim()))
+}
+
+/// Parse a latitude and longitude pair from a line, None for blank or comment lines
+fn parse_line(line: &str) -> Option<Result<(f64, f64), String>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let mut fields = line.split(|c: char| c == ',' || c.is_whitespace()).filter(|f| !f.is_empty());
+    let pair = match (fields.next(), fields.next()) {
+        (Some(lat), Some(lon)) => number("latitude", lat).and_then(|lat| Ok((lat, number("longitude", lon)?))),
+        _ => Err(format!("expected latitude and longitude, found {:?}", line)),
+    };
+    Some(pair)
+}
+
+/// Output line for a location: the region name, preceded by its number with `number`
+fn lookup(lat: f64, lon: f64, number: bool) -> Result<String, fe::RegionError> {
+    let region = fe::geographic_region(lat, lon)?;
+    if number {
+        Ok(format!("{} {}", region.number(), region.name()))
+    } else {
+        Ok(region.name().to_string())
+    }
+}
+
+/// Parse a column given on the command line, numbers are 1-based
+fn column(s: &str) -> Result<fe::Column, String> {
+    match s.parse::<usize>() {
+        Ok(0) => Err("column numbers start at 1".to_string()),
+        Ok(n) => Ok(fe::Column::Index(n - 1)),
+        Err(_) => Ok(fe::Column::Name(s.to_string())),
+    }
+}
+
+/// Parse the annotate options, returning the annotator and the input file, if any
+fn annotate_options(args: &[String]) -> Result<(fe::CsvAnnotator, Option<&str>), String> {
+    let mut fe = fe::CsvAnnotator::new();
+    let mut file = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--lat" => fe = fe.latitude(column(value()?)?),
+            "--lon" => fe = fe.longitude(column(value()?)?),
+            "--delimiter" => {
+                let d = value()?;
+                let mut chars = d.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => fe = fe.delimiter(c),
+                    _ => return Err(format!("delimiter must be a single character, found {:?}", d)),
+                }
+            },
+            "--no-header" => fe = fe.header(false),
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a)),
+            a if file.is_none() => file = Some(a),
+            a => return Err(format!("unexpected argument {}", a)),
+        }
+    }
+    Ok((fe, file))
+}
+
+/// Annotate a CSV catalog
+fn annotate<R: BufRead, W: Write, E: Write>(args: &[String], input: R, out: &mut W, err: &mut E) -> io::Result<i32> {
+    let (fe, file) = match annotate_options(args) {
+        Ok(opts) => opts,
+        Err(msg) => {
+            writeln!(err, "feregion: {}\n{}", msg, USAGE)?;
+            return Ok(USAGE_ERROR);
+        },
+    };
+    let report = match file {
+        None => fe.annotate(input, &mut *out),
+        Some(file) => match std::fs::File::open(file) {
+            Ok(f) => fe.annotate(io::BufReader::new(f), &mut *out),
+            Err(e) => {
+                writeln!(err, "feregion: {}: {}", file, e)?;
+                return Ok(FAILURE);
+            },
+        },
+    };
+    let report = match report {
+        Ok(report) => report,
+        Err(e) => {
+            writeln!(err, "feregion: {}", e)?;
+            return Ok(FAILURE);
+        },
+    };
+    for e in &report.errors {
+        writeln!(err, "feregion: {}", e)?;
+    }
+    Ok(if report.errors.is_empty() { 0 } else { FAILURE })
+}
+
+/// Set or verify the event region of SAC files
+fn sac<W: Write, E: Write>(args: &[String], out: &mut W, err: &mut E) -> io::Result<i32> {
+    let verify = args.iter().any(|a| a == "--verify");
+    let files : Vec<_> = args.iter().filter(|a| *a != "--verify").collect();
+    if let Some(a) = files.iter().find(|a| a.starts_with("--")) {
+        writeln!(err, "feregion: unknown option {}\n{}", a, USAGE)?;
+        return Ok(USAGE_ERROR);
+    }
+    if files.is_empty() {
+        writeln!(err, "feregion: no SAC files\n{}", USAGE)?;
+        return Ok(USAGE_ERROR);
+    }
+    let show = |v: Option<i32>| v.map(|v| v.to_string()).unwrap_or_else(|| "undefined".to_string());
+    let mut status = 0;
+    for file in files {
+        let check = if verify { fe::sac_check_ievreg(file) } else { fe::sac_fill_ievreg(file) };
+        match check {
+            Ok(check) if check.matches() => {},
+            Ok(check) if verify => {
+                writeln!(out, "{}: ievreg {}, expected {}", file, show(check.stored), check.computed)?;
+                status = FAILURE;
+            },
+            Ok(check) => writeln!(out, "{}: ievreg {} -> {}", file, show(check.stored), check.computed)?,
+            Err(e) => {
+                writeln!(err, "feregion: {}: {}", file, e)?;
+                status = FAILURE;
+            },
+        }
+    }
+    Ok(status)
+}
+
+/// Fill the region numbers of a CSS3.0 origin table
+fn origin<R: BufRead, W: Write, E: Write>(args: &[String], input: R, out: &mut W, err: &mut E) -> io::Result<i32> {
+    let mut fe = fe::OriginAnnotator::new();
+    let mut file = None;
+    for arg in args {
+        match arg.as_str() {
+            "--dry-run" => fe = fe.dry_run(true),
+            "--overwrite" => fe = fe.overwrite(true),
+            a if a.starts_with("--") || file.is_some() => {
+                writeln!(err, "feregion: unexpected argument {}\n{}", a, USAGE)?;
+                return Ok(USAGE_ERROR);
+            },
+            a => file = Some(a),
+        }
+    }
+    let report = match file {
+        None => fe.fill(input, &mut *out)?,
+        Some(file) => match std::fs::File::open(file) {
+            Ok(f) => fe.fill(io::BufReader::new(f), &mut *out)?,
+            Err(e) => {
+                writeln!(err, "feregion: {}: {}", file, e)?;
+                return Ok(FAILURE);
+            },
+        },
+    };
+    for e in &report.errors {
+        writeln!(err, "feregion: {}", e)?;
+    }
+    if report.kept > 0 {
+        writeln!(err, "feregion: {} records with differing grn, srn kept, see --overwrite", report.kept)?;
+    }
+    Ok(if report.errors.is_empty() { 0 } else { FAILURE })
+}
+
+fn run<R: BufRead, W: Write, E: Write>(args: &[String], input: R, out: &mut W, err: &mut E) -> io::Result<i32> {
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        write!(out, "{}", USAGE)?;
+        return Ok(0);
+    }
+    if args.first().map(|a| a == "annotate") == Some(true) {
+        return annotate(&args[1..], input, out, err);
+    }
+    if args.first().map(|a| a == "sac") == Some(true) {
+        return sac(&args[1..], out, err);
+    }
+    if args.first().map(|a| a == "origin") == Some(true) {
+        return origin(&args[1..], input, out, err);
+    }
+    let with_number = args.iter().any(|a| a == "-n" || a == "--number");
+    let args : Vec<&str> = args.iter().map(|a| a.as_str()).filter(|a| *a != "-n" && *a != "--number").collect();
+    match args[..] {
+        [] => {
+            let mut status = 0;
+            for (i, line) in input.lines().enumerate() {
+                let pair = match parse_line(&line?) {
+                    None => continue,
+                    Some(pair) => pair,
+                };
+                match pair.and_then(|(lat, lon)| lookup(lat, lon, with_number).map_err(|e| format!("{}: {} {}", e, lat, lon))) {
+                    Ok(line) => writeln!(out, "{}", line)?,
+                    Err(msg) => {
+                        writeln!(err, "feregion: line {}: {}", i + 1, msg)?;
+                        status = FAILURE;
+                    },
+                }
+            }
+            Ok(status)
+        },
+        [lat, lon] => {
+            let pair = number("latitude", lat).and_then(|lat| Ok((lat, number("longitude", lon)?)));
+            let (lat, lon) = match pair {
+                Ok(pair) => pair,
+                Err(msg) => {
+                    writeln!(err, "feregion: {}\n{}", msg, USAGE)?;
+                    return Ok(USAGE_ERROR);
+                },
+            };
+            match lookup(lat, lon, with_number) {
+                Ok(line) => {
+                    writeln!(out, "{}", line)?;
+                    Ok(0)
+                },
+                Err(e) => {
+                    writeln!(err, "feregion: {}: {} {}", e, lat, lon)?;
+                    Ok(FAILURE)
+                },
+            }
+        },
+        _ => {
+            write!(err, "{}", USAGE)?;
+            Ok(USAGE_ERROR)
+        },
+    }
+}
+
+fn main() {
+    let args : Vec<String> = std::env::args().skip(1).collect();
+    let stdin = io::stdin();
+    let status = run(&args, stdin.lock(), &mut io::stdout(), &mut io::stderr()).unwrap_or_else(|e| {
+        eprintln!("feregion: {}", e);
+        FAILURE
+    });
+    std::process::exit(status);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feregion(args: &[&str], input: &str) -> (i32, String, String) {
+        let args : Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        let (mut out, mut err) = (vec![], vec![]);
+        let status = run(&args, input.as_bytes(), &mut out, &mut err).unwrap();
+        (status, String::from_utf8(out).unwrap(), String::from_utf8(err).unwrap())
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(feregion(&["41.440971", "-71.502289"], ""), (0, "SOUTHERN NEW ENGLAND\n".into(), "".into()));
+        assert_eq!(feregion(&["-n", "41.440971", "-71.502289"], "").1, "476 SOUTHERN NEW ENGLAND\n");
+        assert_eq!(feregion(&["41.440971", "-71.502289", "--number"], "").1, "476 SOUTHERN NEW ENGLAND\n");
+        let (status, out, err) = feregion(&["91", "0"], "");
+        assert_eq!((status, out.as_str()), (FAILURE, ""));
+        assert_eq!(err, "feregion: latitude out of range [-90, 90]: 91 0\n");
+        let (status, _, err) = feregion(&["north", "0"], "");
+        assert_eq!(status, USAGE_ERROR);
+        assert!(err.starts_with("feregion: invalid latitude \"north\"\n"));
+        assert_eq!(feregion(&["1"], "").0, USAGE_ERROR);
+        assert_eq!(feregion(&["--help"], "").0, 0);
+    }
+    #[test]
+    fn stdin() {
+        let input = "# lat lon\n41.440971 -71.502289\n\n-42.448299,171.214005\n 0 , 400\nx y\n";
+        let (status, out, err) = feregion(&[], input);
+        assert_eq!(status, FAILURE);
+        assert_eq!(out, "SOUTHERN NEW ENGLAND\nSOUTH ISLAND, NEW ZEALAND\n");
+        assert_eq!(err, "feregion: line 5: longitude out of range: 0 400\n\
+                         feregion: line 6: invalid latitude \"x\"\n");
+        assert_eq!(feregion(&[], "0 0\n").0, 0);
+        assert_eq!(feregion(&["-n"], input).1, "476 SOUTHERN NEW ENGLAND\n162 SOUTH ISLAND, NEW ZEALAND\n");
+    }
+    #[test]
+    fn annotate() {
+        let input = "id\tx\ty\n1\t-71.502289\t41.440971\n2\t0\t-95\n";
+        let (status, out, err) = feregion(&["annotate", "--lat", "3", "--lon", "x", "--delimiter", "\t"], input);
+        assert_eq!(status, FAILURE);
+        assert_eq!(out, "id\tx\ty\tfe_region\tfe_region_name\tfe_seismic_region\n\
+                         1\t-71.502289\t41.440971\t476\tSOUTHERN NEW ENGLAND\t34\n\
+                         2\t0\t-95\t\t\t\n");
+        assert_eq!(err, "feregion: line 3: latitude out of range [-90, 90]\n");
+        assert_eq!(feregion(&["annotate"], "lat,lon\n0,0\n").0, 0);
+        let (status, _, err) = feregion(&["annotate"], "a,b\n");
+        assert_eq!((status, err.as_str()), (FAILURE, "feregion: no latitude column found in the header\n"));
+        assert_eq!(feregion(&["annotate", "--lat", "0"], "").0, USAGE_ERROR);
+        assert_eq!(feregion(&["annotate", "--delimiter"], "").0, USAGE_ERROR);
+        assert_eq!(feregion(&["annotate", "no/such/file.csv"], "").0, FAILURE);
+    }
+    #[test]
+    fn sac() {
+        let path = std::env::temp_dir().join(format!("feregion_{}.sac", std::process::id()));
+        let mut bytes = vec![0u8; fe::SacHeader::LEN];
+        bytes[304..308].copy_from_slice(&6i32.to_le_bytes());
+        bytes[140..144].copy_from_slice(&(-42.45f32).to_le_bytes());
+        bytes[144..148].copy_from_slice(&171.21f32.to_le_bytes());
+        bytes[364..368].copy_from_slice(&12i32.to_le_bytes());
+        std::fs::write(&path, &bytes).unwrap();
+        let file = path.to_str().unwrap();
+
+        let (status, out, _) = feregion(&["sac", "--verify", file], "");
+        assert_eq!((status, out), (FAILURE, format!("{}: ievreg 12, expected 162\n", file)));
+        assert_eq!(feregion(&["sac", file], ""), (0, format!("{}: ievreg 12 -> 162\n", file), "".into()));
+        assert_eq!(feregion(&["sac", "--verify", file], ""), (0, "".into(), "".into()));
+        std::fs::remove_file(&path).unwrap();
+
+        let (status, _, err) = feregion(&["sac", file], "");
+        assert_eq!(status, FAILURE);
+        assert!(err.starts_with(&format!("feregion: {}: ", file)));
+        assert_eq!(feregion(&["sac"], "").0, USAGE_ERROR);
+        assert_eq!(feregion(&["sac", "--fix", file], "").0, USAGE_ERROR);
+    }
+    #[test]
+    fn origin() {
+        let record = |lat: f64, grn: i64, srn: i64| {
+            let line = format!("{:9.4} {:9.4}{:71}{:>8} {:>8}", lat, 171.214, "", grn, srn);
+            format!("{:237}\n", line)
+        };
+        let input = record(-42.4483, -1, -1) + &record(-42.4483, 7, -1) + &record(95.0, -1, -1);
+        let (status, out, err) = feregion(&["origin"], &input);
+        assert_eq!(status, FAILURE);
+        assert_eq!(out, record(-42.4483, 162, 11) + &record(-42.4483, 7, 1) + &record(95.0, -1, -1));
+        assert_eq!(err, "feregion: line 3: latitude out of range [-90, 90]\n\
+                         feregion: 1 records with differing grn, srn kept, see --overwrite\n");
+        let (_, diff, _) = feregion(&["origin", "--dry-run", "--overwrite"], &input);
+        assert_eq!(diff.lines().filter(|l| l.starts_with('>')).count(), 2);
+        assert_eq!(feregion(&["origin", "--bogus"], "").0, USAGE_ERROR);
+        assert_eq!(feregion(&["origin", "no/such/db.origin"], "").0, FAILURE);
+    }
+}
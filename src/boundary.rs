@@ -0,0 +1,129 @@
+use crate::{Lookup, RegionError};
+use crate::geometry;
+
+/// Distance from a location to the nearest edge of its region
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct BoundaryDistance {
+    /// Great circle distance to the nearest boundary, km
+    pub distance: f64,
+    /// Geographic Region Number on the other side of the boundary
+    pub region: usize,
+    /// Azimuth to the nearest boundary point, degrees clockwise from north
+    pub azimuth: f64,
+}
+
+/// Initial search radius, km
+const START: f64 = 100.0;
+
+impl Lookup {
+    /// Get the distance to the nearest boundary with another region,
+    /// see [`distance_to_boundary`](crate::distance_to_boundary)
+    pub fn distance_to_boundary(&self, lat: f64, lon: f64) -> Result<BoundaryDistance, RegionError> {
+        let here = self.region_number(lat, lon)?;
+        let (lat, lon) = self.normalize(lat, lon)?;
+        let mut km = START;
+        loop {
+            let (dlat, dlon) = geometry::span(lat, km);
+            let mut best : Option<BoundaryDistance> = None;
+            for cell in geometry::window(lat, lon, dlat, dlon) {
+                let (clat, clon) = cell.center();
+                let region = self.region_number(clat, clon)?;
+                if region == here {
+                    continue;
+                }
+                let ((plat, plon), distance) = geometry::nearest_in_cell(lat, lon, &cell);
+                if best.map(|b| (distance, region) < (b.distance, b.region)).unwrap_or(true) {
+                    // On the boundary itself, point towards the neighbouring cell
+                    let azimuth = if distance > 0.0 {
+                        geometry::azimuth(lat, lon, plat, plon)
+                    } else {
+                        geometry::azimuth(lat, lon, clat, clon)
+                    };
+                    best = Some(BoundaryDistance { distance, region, azimuth });
+                }
+            }
+            // Cells outside the window are farther than km
+            match best {
+                Some(b) if b.distance <= km || dlat >= 180.0 => return Ok(b),
+                _ => km *= 2.0,
+            }
+        }
+    }
+}
+
+/// Get the distance to the nearest boundary between the region containing
+/// a location at (`lat`,`lon`) and any other region
+///
+/// Boundaries are the edges of 1 x 1 degree cells whose neighbour belongs
+/// to a different region.  The distance is measured along a great circle
+/// to the nearest point of such a neighbouring cell.  A location on a grid
+/// line is assigned to one side as in [`region_number`](crate::region_number),
+/// and is at distance 0 if the other side is a different region.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  // Just north of 41 N, near the edge of SOUTHERN NEW ENGLAND
+///  let b = fe::distance_to_boundary(41.05, -72.5).unwrap();
+///  assert_eq!(b.region, fe::region_number(40.95, -72.5).unwrap());
+///  assert!((b.distance - 5.56).abs() < 0.01);
+///  assert!((b.azimuth - 180.0).abs() < 1e-9);
+/// ```
+///
+/// # Arguments
+///   - lat - Latitude  [-90, 90]
+///   - lon - Longitude [-360, 360]
+///
+/// # Returns
+///   - Distance in km, neighbouring region and azimuth to the boundary
+///
+pub fn distance_to_boundary(lat: f64, lon: f64) -> Result<BoundaryDistance, RegionError> {
+    Lookup::new().distance_to_boundary(lat, lon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cell;
+
+    // Nearest different cell by scanning the whole grid
+    fn brute_force(lat: f64, lon: f64) -> (f64, usize) {
+        let here = crate::region_number(lat, lon).unwrap();
+        let mut best = (f64::INFINITY, 0);
+        for ilat in -90 .. 90 {
+            for ilon in -180 .. 180 {
+                let cell = Cell::new(ilat, ilon).unwrap();
+                let (clat, clon) = cell.center();
+                let region = crate::region_number(clat, clon).unwrap();
+                if region != here {
+                    let d = geometry::nearest_in_cell(lat, lon, &cell).1;
+                    if (d, region) < best {
+                        best = (d, region);
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn on_boundary() {
+        let b = distance_to_boundary(42.0, -73.0).unwrap();
+        assert_eq!(b.distance, 0.0);
+        assert_ne!(b.region, crate::region_number(42.0, -73.0).unwrap());
+    }
+    #[test]
+    fn agrees_with_brute_force() {
+        for &(lat, lon) in &[(41.05, -72.5), (-89.5, 0.0), (0.3, 179.9), (-20.2, -150.7), (75.0, 30.0), (10.0, -30.0)] {
+            let b = distance_to_boundary(lat, lon).unwrap();
+            let (d, region) = brute_force(lat, lon);
+            assert!((b.distance - d).abs() < 1e-9, "{} {}: {} {}", lat, lon, b.distance, d);
+            assert_eq!(b.region, region);
+            assert!((0.0 .. 360.0).contains(&b.azimuth));
+        }
+    }
+    #[test]
+    fn bad_location() {
+        assert_eq!(distance_to_boundary(91.0, 0.0), Err(RegionError::BadLatitude));
+        assert_eq!(distance_to_boundary(0.0, f64::NAN), Err(RegionError::NonFiniteLongitude));
+    }
+}
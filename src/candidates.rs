@@ -0,0 +1,150 @@
+use crate::{Lookup, RegionError};
+use crate::geometry;
+
+/// Distance tolerance for boundary-aware lookups
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub enum Tolerance {
+    /// Degrees of latitude and longitude, measured independently
+    Degrees(f64),
+    /// Great circle distance in km
+    Kilometers(f64),
+}
+
+/// Regions within a tolerance of a location
+#[derive(Debug,Clone,PartialEq)]
+pub struct Candidates {
+    /// Region returned by the standard lookup
+    pub region: usize,
+    /// All regions within the tolerance, including `region`, in increasing order
+    pub regions: Vec<usize>,
+}
+
+impl Candidates {
+    /// Check if a single region lies within the tolerance
+    pub fn is_unique(&self) -> bool {
+        self.regions.len() == 1
+    }
+}
+
+impl Lookup {
+    /// Get every region within `tol` of the location at (`lat`,`lon`),
+    /// see [`region_candidates`](crate::region_candidates)
+    pub fn region_candidates(&self, lat: f64, lon: f64, tol: Tolerance) -> Result<Candidates, RegionError> {
+        let region = self.region_number(lat, lon)?;
+        let (lat, lon) = self.normalize(lat, lon)?;
+        let (dlat, dlon, km) = match tol {
+            Tolerance::Degrees(d) if d.is_finite() && d >= 0.0 => (d, d, None),
+            Tolerance::Kilometers(k) if k.is_finite() && k >= 0.0 => {
+                let (dlat, dlon) = geometry::span(lat, k);
+                (dlat, dlon, Some(k))
+            },
+            _ => return Err(RegionError::BadTolerance),
+        };
+        let mut regions = vec![region];
+        for cell in geometry::window(lat, lon, dlat, dlon) {
+            let inside = match km {
+                Some(k) => geometry::nearest_in_cell(lat, lon, &cell).1 <= k,
+                None => {
+                    let dy = (lat - lat.clamp(cell.south(), cell.north())).abs();
+                    let dx = geometry::wrap(lon - cell.west());
+                    let dx = if (0.0 ..= 1.0).contains(&dx) { 0.0 } else { dx.abs().min((dx - 1.0).abs()) };
+                    dy <= dlat && dx <= dlon
+                },
+            };
+            if inside {
+                let (clat, clon) = cell.center();
+                regions.push(self.region_number(clat, clon)?);
+            }
+        }
+        regions.sort_unstable();
+        regions.dedup();
+        Ok(Candidates { region, regions })
+    }
+}
+
+/// Get every region within a tolerance of a location at (`lat`,`lon`)
+///
+/// Locations are truncated to whole degrees, so a location on or near a
+/// 1 degree grid line is assigned to one side arbitrarily.  All regions with
+/// a 1 x 1 degree cell within the tolerance are returned, with the standard
+/// lookup result, allowing ambiguous lookups to be flagged.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  use fe::Tolerance;
+///  // Corner of SOUTHERN NEW ENGLAND
+///  let c = fe::region_candidates(42.0, -73.0, Tolerance::Kilometers(1.0)).unwrap();
+///  assert_eq!(c.region, fe::region_number(42.0, -73.0).unwrap());
+///  assert!(c.regions.contains(&476));
+///  assert!(!c.is_unique());
+///
+///  let c = fe::region_candidates(-42.448299, 171.214005, Tolerance::Degrees(0.1)).unwrap();
+///  assert!(c.is_unique());
+/// ```
+///
+/// # Arguments
+///   - lat - Latitude  [-90, 90]
+///   - lon - Longitude [-360, 360]
+///   - tol - Tolerance, in degrees or km
+///
+/// # Returns
+///   - Candidate regions
+///
+pub fn region_candidates(lat: f64, lon: f64, tol: Tolerance) -> Result<Candidates, RegionError> {
+    Lookup::new().region_candidates(lat, lon, tol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_tolerance() {
+        for &(lat, lon) in [(-42.5, 171.5), (0.5, 0.5), (-89.5, 10.5)].iter() {
+            let c = region_candidates(lat, lon, Tolerance::Degrees(0.0)).unwrap();
+            assert!(c.is_unique());
+            assert_eq!(c.regions, [crate::region_number(lat, lon).unwrap()]);
+        }
+    }
+    #[test]
+    fn grid_line() {
+        // Exactly on a corner, all four cells touch the location
+        let (lat, lon) = (42.0, -73.0);
+        let c = region_candidates(lat, lon, Tolerance::Degrees(0.0)).unwrap();
+        let mut near : Vec<_> = [(41.5, -73.5), (42.5, -73.5), (41.5, -72.5), (42.5, -72.5)].iter()
+            .map(|&(a, b)| crate::region_number(a, b).unwrap())
+            .collect();
+        near.sort_unstable();
+        near.dedup();
+        assert_eq!(c.regions, near);
+        assert!(!c.is_unique());
+    }
+    #[test]
+    fn antimeridian() {
+        let c = region_candidates(-15.5, 179.99, Tolerance::Kilometers(5.0)).unwrap();
+        assert!(c.regions.contains(&crate::region_number(-15.5, -179.5).unwrap()));
+        assert!(c.regions.contains(&crate::region_number(-15.5, 179.5).unwrap()));
+    }
+    #[test]
+    fn pole() {
+        let c = region_candidates(89.9, 0.0, Tolerance::Kilometers(50.0)).unwrap();
+        let all : Vec<_> = (-180 .. 180).map(|lon| crate::region_number(89.5, lon as f64 + 0.5).unwrap()).collect();
+        for r in all {
+            assert!(c.regions.contains(&r));
+        }
+    }
+    #[test]
+    fn bad_tolerance() {
+        assert_eq!(region_candidates(0., 0., Tolerance::Degrees(-1.0)), Err(RegionError::BadTolerance));
+        assert_eq!(region_candidates(0., 0., Tolerance::Kilometers(f64::NAN)), Err(RegionError::BadTolerance));
+        assert_eq!(region_candidates(f64::NAN, 0., Tolerance::Degrees(1.0)), Err(RegionError::NonFiniteLatitude));
+        assert_eq!(region_candidates(0., 0., Tolerance::Degrees(f64::INFINITY)), Err(RegionError::BadTolerance));
+        assert_eq!(region_candidates(0., 0., Tolerance::Kilometers(f64::INFINITY)), Err(RegionError::BadTolerance));
+        // Large but finite tolerances cover the whole globe
+        let every: Vec<usize> = (1..=crate::NAMES.len()).filter(|&r| crate::cells(r).next().is_some()).collect();
+        let all = region_candidates(0., 0., Tolerance::Degrees(1e300)).unwrap();
+        assert_eq!(all.regions, every);
+        let all = region_candidates(0., 0., Tolerance::Kilometers(1e300)).unwrap();
+        assert_eq!(all.regions, every);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::Regionalization;
+
+/// A 1 x 1 degree cell of the Flinn_Engdahl grid
+///
+/// Positions are truncated towards zero within each quadrant, so a cell
+/// north of the equator holds latitudes `[south, north)` and one south of
+/// the equator holds `(south, north]`; likewise for longitude either side
+/// of the prime meridian.  The equator and prime meridian themselves belong
+/// to the northern and eastern cells.
+///
+#[derive(Debug,Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub struct Cell {
+    pub(crate) lat: i16,
+    pub(crate) lon: i16,
+}
+
+impl Cell {
+    /// Cell with its south west corner at (`lat`, `lon`)
+    ///
+    /// # Arguments
+    ///   - lat - Latitude  [-90, 89]
+    ///   - lon - Longitude [-180, 179]
+    ///
+    pub fn new(lat: i16, lon: i16) -> Option<Cell> {
+        if !(-90 .. 90).contains(&lat) || !(-180 .. 180).contains(&lon) {
+            return None;
+        }
+        Some(Cell { lat, lon })
+    }
+    /// Southern edge, degrees
+    pub fn south(&self) -> f64 { self.lat as f64 }
+    /// Northern edge, degrees
+    pub fn north(&self) -> f64 { self.lat as f64 + 1.0 }
+    /// Western edge, degrees
+    pub fn west(&self) -> f64 { self.lon as f64 }
+    /// Eastern edge, degrees
+    pub fn east(&self) -> f64 { self.lon as f64 + 1.0 }
+    /// Center of the cell (lat, lon)
+    pub fn center(&self) -> (f64, f64) {
+        (self.lat as f64 + 0.5, self.lon as f64 + 0.5)
+    }
+    /// Check if the location (`lat`, `lon`) falls in this cell
+    ///
+    /// Longitude is expected in [-180, 180]
+    pub fn contains(&self, lat: f64, lon: f64) -> bool {
+        let inside = |v: f64, lo: f64| {
+            // Zero, including negative zero, belongs to the positive quadrant
+            if lo >= 0.0 { v >= lo && v < lo + 1.0 } else { v < 0.0 && v > lo && v <= lo + 1.0 }
+        };
+        inside(lat, self.south()) && inside(lon, self.west())
+    }
+    /// Surface area of the cell on a sphere of radius 6371 km, km^2
+    pub fn area(&self) -> f64 {
+        let r = crate::geometry::EARTH_RADIUS;
+        let (s, n) = (self.south().to_radians(), self.north().to_radians());
+        r * r * 1.0_f64.to_radians() * (n.sin() - s.sin())
+    }
+}
+
+/// Region number of every cell, indexed by south west corner
+pub(crate) struct CellGrid {
+    regions: Vec<usize>,
+}
+
+impl CellGrid {
+    /// Region number of the cell with its south west corner at (`lat`, `lon`)
+    ///
+    /// Returns `None` outside of [-90, 89] x [-180, 179]
+    pub fn get(&self, lat: i16, lon: i16) -> Option<usize> {
+        Cell::new(lat, lon).map(|c| self.regions[Self::index(c.lat, c.lon)])
+    }
+    /// Cells belonging to `region`
+    pub fn cells(&self, region: usize) -> impl Iterator<Item = Cell> + '_ {
+        self.regions.iter().enumerate()
+            .filter(move |(_, &r)| r == region)
+            .map(|(i, _)| Cell { lat: (i / 360) as i16 - 90, lon: (i % 360) as i16 - 180 })
+    }
+    fn index(lat: i16, lon: i16) -> usize {
+        (lat + 90) as usize * 360 + (lon + 180) as usize
+    }
+}
+
+impl Regionalization {
+    /// Region number of every cell
+    pub(crate) fn cell_grid(&self) -> CellGrid {
+        let mut regions = vec![0; 360 * 180];
+        for (lat, lon, r) in self.tier_cells() {
+            regions[CellGrid::index(lat, lon)] = r;
+        }
+        CellGrid { regions }
+    }
+    /// Iterate over the cells assigned to geographic `region`, see [`cells`](crate::cells)
+    pub fn cells(&self, region: usize) -> impl Iterator<Item = Cell> + '_ {
+        self.tier_cells()
+            .filter(move |&(_, _, r)| r == region)
+            .map(|(lat, lon, _)| Cell { lat, lon })
+    }
+}
+
+/// Iterate over the 1 x 1 degree cells belonging to a geographic region
+///
+/// Cells are walked from the latitude tiers quadrant by quadrant (NE, NW, SE, SW)
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let cells : Vec<_> = fe::cells(476).collect(); // SOUTHERN NEW ENGLAND
+///  assert!(cells.iter().any(|c| c.contains(41.440971, -71.502289)));
+///  for cell in &cells {
+///      let (lat, lon) = cell.center();
+///      assert_eq!(fe::region_number(lat, lon), Ok(476));
+///  }
+/// ```
+///
+/// # Arguments
+///   - region - Geographic Region Number [1, 757]
+///
+/// # Returns
+///   - Cells within the region, empty for an unknown region
+///
+pub fn cells(region: usize) -> impl Iterator<Item = Cell> {
+    Regionalization::fe1995().cells(region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cell;
+
+    #[test]
+    fn cell_bounds() {
+        assert_eq!(Cell::new(90, 0), None);
+        assert_eq!(Cell::new(0, 180), None);
+        assert_eq!(Cell::new(-91, 0), None);
+        let c = Cell::new(-1, -1).unwrap();
+        assert!(c.contains(-0.5, -0.5));
+        assert!(c.contains(-1.0 + 1e-9, 0.0 - 1e-9));
+        assert!(!c.contains(0.0, -0.5));
+        assert!(!c.contains(-1.0, -0.5));
+        let c = Cell::new(0, 0).unwrap();
+        assert!(c.contains(0.0, 0.0));
+        assert!(c.contains(-0.0, -0.0));
+        assert!(!c.contains(1.0, 0.5));
+    }
+    #[test]
+    fn total_cells() {
+        let n : usize = (1 ..= 757).map(|r| crate::cells(r).count()).sum();
+        assert_eq!(n, 360 * 180);
+        assert_eq!(crate::cells(0).count(), 0);
+        assert_eq!(crate::cells(758).count(), 0);
+    }
+    #[test]
+    fn grid_matches_cells() {
+        let grid = crate::Regionalization::fe1995().cell_grid();
+        for &r in [1, 162, 476, 729].iter() {
+            let a : Vec<_> = grid.cells(r).collect();
+            let mut b : Vec<_> = crate::cells(r).collect();
+            b.sort();
+            assert_eq!(a, b);
+        }
+        assert_eq!(grid.get(-90, -180), Some(729));
+        assert_eq!(grid.get(90, 0), None);
+    }
+    #[test]
+    fn total_area() {
+        let area : f64 = crate::cells(729).chain(crate::cells(1)).map(|c| c.area()).sum();
+        assert!(area > 0.0);
+        let globe : f64 = (-90 .. 90)
+            .flat_map(|lat| (-180 .. 180).map(move |lon| Cell::new(lat, lon).unwrap().area()))
+            .sum();
+        let r = 6371.0_f64;
+        assert!((globe - 4.0 * std::f64::consts::PI * r * r).abs() < 1.0);
+    }
+}
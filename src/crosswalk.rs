@@ -0,0 +1,118 @@
+use crate::{RegionError, Regionalization};
+
+/// Overlap of a region with a region from another regionalization
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Overlap {
+    /// Region number in the target regionalization
+    pub region: usize,
+    /// Number of 1 x 1 degree cells shared with the source region
+    pub cells: usize,
+    /// Fraction of the source region's cells that fall in `region`
+    pub fraction: f64,
+}
+
+/// Cross-walk a region number from one regionalization into another
+///
+/// Every 1 x 1 degree cell of `region` in `from`, walked from its latitude
+/// tiers, is looked up in the cell grid built from the tiers of `to`.  Overlapping
+/// regions are returned in decreasing order of shared cells; the fractions
+/// sum to 1.
+///
+/// Only the 1995 tables are built in, so cross-walking needs a second set
+/// of tables loaded with [`Regionalization::from_dir`], for example a
+/// locally modified regionalization.  The 1978 revision is not available,
+/// see the [crate documentation](crate#revisions).
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let fe95 = fe::Regionalization::fe1995();
+///  let local = fe::Regionalization::from_dir("data").unwrap();
+///  let over = fe::crosswalk(729, &local, fe95).unwrap();
+///  assert_eq!(over.len(), 1);
+///  assert_eq!(over[0].region, 729);
+///  assert_eq!(over[0].fraction, 1.0);
+/// ```
+///
+/// # Arguments
+///   - region - Region number in `from`
+///   - from - Regionalization `region` is defined in
+///   - to - Regionalization to express the overlaps in
+///
+/// # Returns
+///   - Overlapping regions in `to`
+///
+pub fn crosswalk(region: usize, from: &Regionalization, to: &Regionalization) -> Result<Vec<Overlap>, RegionError> {
+    if from.name(region).is_none() {
+        return Err(RegionError::BadRegionNumber);
+    }
+    let grid = to.cell_grid();
+    let mut counts = vec![0usize; to.number_of_regions() + 1];
+    for cell in from.cells(region) {
+        // Every regionalization covers the whole 1 x 1 degree grid
+        counts[grid.get(cell.lat, cell.lon).unwrap()] += 1;
+    }
+    let total : usize = counts.iter().sum();
+    let mut out : Vec<_> = counts.iter().enumerate()
+        .filter(|(_, &n)| n > 0)
+        .map(|(i, &n)| Overlap { region: i, cells: n, fraction: n as f64 / total as f64 })
+        .collect();
+    out.sort_by(|a, b| b.cells.cmp(&a.cells).then(a.region.cmp(&b.region)));
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Regionalization, RegionError};
+
+    #[test]
+    fn identity() {
+        let rev = Regionalization::fe1995();
+        for n in (1 ..= rev.number_of_regions()).step_by(37) {
+            let over = crate::crosswalk(n, rev, rev).unwrap();
+            assert_eq!(over.len(), 1, "{}", n);
+            assert_eq!(over[0].region, n);
+            assert_eq!(over[0].fraction, 1.0);
+        }
+    }
+    /// 1995 tables with the first NE segment (0N, 0-9E) moved from 561 to 476
+    fn modified() -> Regionalization {
+        let read = |file: &str| std::fs::read_to_string(std::path::Path::new("data").join(file)).unwrap();
+        let ne = read("nesect.asc").replacen("0 561", "0 476", 1);
+        let (nw, se, sw) = (read("nwsect.asc"), read("sesect.asc"), read("swsect.asc"));
+        Regionalization::from_readers(read("quadsidx.asc").as_bytes(),
+                                      [ne.as_bytes(), nw.as_bytes(), se.as_bytes(), sw.as_bytes()],
+                                      read("names.asc").as_bytes()).unwrap()
+    }
+    #[test]
+    fn moved_cells() {
+        let (fe95, local) = (Regionalization::fe1995(), modified());
+        let n561 = fe95.cells(561).count();
+        assert_eq!(fe95.cells(476).count(), 7);
+        let over = crate::crosswalk(561, fe95, &local).unwrap();
+        assert_eq!(over.iter().map(|o| (o.region, o.cells)).collect::<Vec<_>>(),
+                   vec![(561, n561 - 9), (476, 9)]);
+        assert_eq!(over[1].fraction, 9.0 / n561 as f64);
+        assert_eq!(over.iter().map(|o| o.fraction).sum::<f64>(), 1.0);
+
+        let over = crate::crosswalk(476, &local, fe95).unwrap();
+        assert_eq!(over.iter().map(|o| (o.region, o.cells)).collect::<Vec<_>>(),
+                   vec![(561, 9), (476, 7)]);
+        // Every cell of 561 in the modified tables is still 561 in 1995
+        let over = crate::crosswalk(561, &local, fe95).unwrap();
+        assert_eq!(over.iter().map(|o| (o.region, o.fraction)).collect::<Vec<_>>(), vec![(561, 1.0)]);
+    }
+    #[test]
+    fn unused_regions() {
+        // Regions without a full 1 x 1 degree cell in the 1995 tiers
+        let rev = Regionalization::fe1995();
+        for &n in [172, 299, 550].iter() {
+            assert_eq!(crate::crosswalk(n, rev, rev), Ok(vec![]));
+        }
+    }
+    #[test]
+    fn bad_region() {
+        let rev = Regionalization::fe1995();
+        assert_eq!(crate::crosswalk(0, rev, rev), Err(RegionError::BadRegionNumber));
+        assert_eq!(crate::crosswalk(758, rev, rev), Err(RegionError::BadRegionNumber));
+    }
+}
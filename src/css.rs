@@ -0,0 +1,344 @@
+//! Region numbers, `grn` and `srn`, of CSS3.0 `origin` tables
+//!
+//! The `origin` relation is a fixed width text table, one 237 character
+//! record per line.  Records are kept as their text and only the `grn` and
+//! `srn` columns are rewritten; every other column, and the line ending,
+//! is copied byte for byte.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+
+use crate::{Lookup, RegionError};
+
+/// Length of an `origin` record, without the line ending
+pub const ORIGIN_RECORD_LEN: usize = 237;
+
+/// Byte ranges of the columns used
+const LAT: Range<usize> = 0 .. 9;
+const LON: Range<usize> = 10 .. 19;
+const GRN: Range<usize> = 90 .. 98;
+const SRN: Range<usize> = 99 .. 107;
+
+/// Null value of `lat` and `lon`
+const NULL_LOCATION: f64 = -999.0;
+/// Null value of `grn` and `srn`
+pub const NULL_REGION: i64 = -1;
+
+/// Problem with a single `origin` record
+#[derive(Debug,Clone,PartialEq)]
+pub enum OriginProblem {
+    /// The record is shorter than [`ORIGIN_RECORD_LEN`]
+    Short(usize),
+    /// A column is not a number
+    Unparsable(&'static str, String),
+    /// `lat` or `lon` is null
+    NoLocation,
+    /// The location is out of range
+    Region(RegionError),
+}
+
+/// A record whose region numbers could not be found; it is left unchanged
+#[derive(Debug,Clone,PartialEq)]
+pub struct OriginError {
+    /// Line number, 1-based
+    pub line: usize,
+    /// What is wrong with it
+    pub problem: OriginProblem,
+}
+
+impl fmt::Display for OriginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.problem {
+            OriginProblem::Short(len) => write!(f, "record of {} characters, expected {}", len, ORIGIN_RECORD_LEN),
+            OriginProblem::Unparsable(what, value) => write!(f, "invalid {} {:?}", what, value),
+            OriginProblem::NoLocation => write!(f, "lat, lon is null"),
+            OriginProblem::Region(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Outcome of filling an `origin` table
+#[derive(Debug,Clone,Default,PartialEq)]
+pub struct OriginReport {
+    /// Number of records
+    pub records: usize,
+    /// Records whose `grn` or `srn` was, or in a dry run would be, changed
+    pub changed: usize,
+    /// Records with a non-null `grn` or `srn` that disagrees with `lat` and
+    /// `lon` or with each other, left as it is
+    pub kept: usize,
+    /// Records that could not be filled
+    pub errors: Vec<OriginError>,
+}
+
+/// A record of a CSS3.0 `origin` table
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let mut line = format!("{:9.4} {:9.4}", -42.4483, 171.214);
+///  line += &" ".repeat(71);
+///  line += &format!("{:>8} {:>8}", -1, -1);
+///  line += &" ".repeat(fe::ORIGIN_RECORD_LEN - line.len());
+///
+///  let mut origin = fe::OriginRecord::parse(&line).unwrap();
+///  assert_eq!((origin.lat().unwrap(), origin.lon().unwrap()), (-42.4483, 171.214));
+///  assert_eq!((origin.grn().unwrap(), origin.srn().unwrap()), (-1, -1));
+///  origin.set_regions(162, 11);
+///  assert_eq!((origin.grn().unwrap(), origin.srn().unwrap()), (162, 11));
+///  assert_eq!(&origin.as_str()[90..107], "     162       11");
+/// ```
+///
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct OriginRecord {
+    text: String,
+}
+
+impl OriginRecord {
+    /// Record from a line of the table, without its line ending
+    pub fn parse(line: &str) -> Result<Self, OriginProblem> {
+        let len = line.len();
+        if len < ORIGIN_RECORD_LEN {
+            return Err(OriginProblem::Short(len));
+        }
+        // Columns are byte ranges; those used must not split a character
+        if !line.as_bytes()[.. SRN.end].is_ascii() {
+            return Err(OriginProblem::Unparsable("record", line.chars().take(SRN.end).collect()));
+        }
+        Ok(OriginRecord { text: line.to_string() })
+    }
+    /// Record text
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+    fn column<T: std::str::FromStr>(&self, what: &'static str, range: Range<usize>) -> Result<T, OriginProblem> {
+        let value = self.text[range].trim();
+        value.parse().map_err(|_| OriginProblem::Unparsable(what, value.to_string()))
+    }
+    /// Latitude
+    pub fn lat(&self) -> Result<f64, OriginProblem> {
+        self.column("lat", LAT)
+    }
+    /// Longitude
+    pub fn lon(&self) -> Result<f64, OriginProblem> {
+        self.column("lon", LON)
+    }
+    /// Geographic region number, -1 if null
+    pub fn grn(&self) -> Result<i64, OriginProblem> {
+        self.column("grn", GRN)
+    }
+    /// Seismic region number, -1 if null
+    pub fn srn(&self) -> Result<i64, OriginProblem> {
+        self.column("srn", SRN)
+    }
+    /// Set the geographic and seismic region numbers
+    pub fn set_regions(&mut self, grn: i64, srn: i64) {
+        self.text.replace_range(GRN, &format!("{:>8}", grn));
+        self.text.replace_range(SRN, &format!("{:>8}", srn));
+    }
+}
+
+/// Fills the `grn` and `srn` columns of CSS3.0 `origin` tables
+///
+/// A null, -1, `grn` is filled from `lat` and `lon` and a null `srn` from
+/// the `grn`, so the pair stays consistent; a stored `srn` that contradicts
+/// the `grn` leaves the record unchanged.  With
+/// [`overwrite`](Self::overwrite) non-null values that differ are replaced
+/// too.  In a [`dry_run`](Self::dry_run) the output is a diff, in the
+/// format of `diff` without options, of the records that would change.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let mut line = format!("{:9.4} {:9.4}", 41.441, -71.5023);
+///  line += &" ".repeat(71);
+///  line += &format!("{:>8} {:>8}", -1, -1);
+///  line += &" ".repeat(fe::ORIGIN_RECORD_LEN - line.len());
+///  let table = format!("{}\n", line);
+///
+///  let mut out = vec![];
+///  let report = fe::OriginAnnotator::new().fill(table.as_bytes(), &mut out).unwrap();
+///  assert_eq!((report.records, report.changed), (1, 1));
+///  let filled = String::from_utf8(out).unwrap();
+///  assert_eq!(&filled[90..107], "     476       34");
+///  assert_eq!(filled.replace("     476       34", "      -1       -1"), table);
+///
+///  let mut diff = vec![];
+///  fe::OriginAnnotator::new().dry_run(true).fill(table.as_bytes(), &mut diff).unwrap();
+///  assert_eq!(String::from_utf8(diff).unwrap(), format!("1c1\n< {}---\n> {}", table, filled));
+/// ```
+///
+#[derive(Debug,Clone,Default)]
+pub struct OriginAnnotator {
+    overwrite: bool,
+    dry_run: bool,
+    lookup: Lookup,
+}
+
+impl OriginAnnotator {
+    /// Default annotator, filling null region numbers only
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set whether non-null region numbers that differ are replaced
+    pub fn overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+    /// Set whether to write a diff of the changes instead of the table
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+    /// Set the lookup used to find regions
+    pub fn lookup(mut self, lookup: Lookup) -> Self {
+        self.lookup = lookup;
+        self
+    }
+    /// Region numbers of a record
+    fn regions(&self, origin: &OriginRecord) -> Result<(i64, i64), OriginProblem> {
+        let (lat, lon) = (origin.lat()?, origin.lon()?);
+        if lat == NULL_LOCATION || lon == NULL_LOCATION {
+            return Err(OriginProblem::NoLocation);
+        }
+        let n = self.lookup.region_number(lat, lon).map_err(OriginProblem::Region)?;
+        Ok((n as i64, crate::SEISMIC_REGIONS[n-1] as i64))
+    }
+    /// Filled record, None if it is unchanged
+    fn fill_record(&self, line: &str, report: &mut OriginReport) -> Result<Option<OriginRecord>, OriginProblem> {
+        let mut origin = OriginRecord::parse(line)?;
+        let stored = (origin.grn()?, origin.srn()?);
+        let computed = self.regions(&origin)?;
+        let grn = if stored.0 == NULL_REGION || self.overwrite { computed.0 } else { stored.0 };
+        if grn < 1 || grn as usize > crate::SEISMIC_REGIONS.len() {
+            return Err(OriginProblem::Region(RegionError::BadRegionNumber));
+        }
+        // srn follows grn, stored or filled, so the pair stays consistent
+        let seismic = crate::SEISMIC_REGIONS[grn as usize - 1] as i64;
+        let srn = if stored.1 == NULL_REGION || self.overwrite { seismic } else { stored.1 };
+        if srn != seismic {
+            // A stored srn that contradicts the grn, filled or not
+            report.kept += 1;
+            return Ok(None);
+        }
+        let filled = (grn, srn);
+        if filled != computed {
+            report.kept += 1;
+        }
+        if filled == stored {
+            return Ok(None);
+        }
+        report.changed += 1;
+        origin.set_regions(filled.0, filled.1);
+        Ok(Some(origin))
+    }
+    /// Fill the region numbers of the `origin` table from `input`, writing
+    /// the table, or in a dry run the diff, to `output`
+    ///
+    /// Records that cannot be filled are copied unchanged and listed in the report
+    pub fn fill<R: BufRead, W: Write>(&self, mut input: R, mut output: W) -> io::Result<OriginReport> {
+        let mut report = OriginReport::default();
+        let mut line = String::new();
+        let mut n = 0;
+        loop {
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                break;
+            }
+            n += 1;
+            let body = line.trim_end_matches(['\n', '\r']);
+            let ending = &line[body.len() ..];
+            if body.trim().is_empty() {
+                if !self.dry_run {
+                    output.write_all(line.as_bytes())?;
+                }
+                continue;
+            }
+            report.records += 1;
+            let filled = self.fill_record(body, &mut report).unwrap_or_else(|problem| {
+                report.errors.push(OriginError { line: n, problem });
+                None
+            });
+            match (filled, self.dry_run) {
+                (Some(origin), true) => write!(output, "{}c{}\n< {}\n---\n> {}\n", n, n, body, origin.as_str())?,
+                (Some(origin), false) => write!(output, "{}{}", origin.as_str(), ending)?,
+                (None, true) => {},
+                (None, false) => output.write_all(line.as_bytes())?,
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(lat: f64, lon: f64, grn: i64, srn: i64) -> String {
+        format!("{:9.4} {:9.4} {:9.4} {:17.5} {:8} {:8} {:8} {:4} {:4} {:4} {:8} {:8} {:<7} {:9.4} {:1} \
+                 {:7.2} {:8} {:7.2} {:8} {:7.2} {:8} {:<15} {:<15} {:8} {:17.5}",
+                lat, lon, 10.0, 1577836800.0, 1, 1, 2020001, 12, 10, -1, grn, srn, "eq", -999.0, "f",
+                3.1, 1, -999.0, -1, -999.0, -1, "locsat:iasp91", "ISC", -1, 1577836900.0)
+    }
+
+    #[test]
+    fn layout() {
+        let r = record(-42.4483, 171.214, 7, -1);
+        assert_eq!(r.len(), ORIGIN_RECORD_LEN);
+        let origin = OriginRecord::parse(&r).unwrap();
+        assert_eq!((origin.lat(), origin.lon()), (Ok(-42.4483), Ok(171.214)));
+        assert_eq!((origin.grn(), origin.srn()), (Ok(7), Ok(-1)));
+        assert_eq!(OriginRecord::parse(&r[.. 200]), Err(OriginProblem::Short(200)));
+        let r = r.replacen("  10.0000", " 10.0000é", 1);
+        assert!(matches!(OriginRecord::parse(&r), Err(OriginProblem::Unparsable("record", _))));
+    }
+    #[test]
+    fn fill() {
+        let table = [record(41.441, -71.5023, -1, -1), record(-42.4483, 171.214, 7, 3),
+                     record(-999.0, -999.0, -1, -1), record(95.0, 0.0, -1, -1), "short".to_string(),
+                     record(0.5, 0.5, 561, 37)].join("\r\n") + "\r\n\r\n";
+        let mut out = vec![];
+        let report = OriginAnnotator::new().fill(table.as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!((report.records, report.changed, report.kept), (6, 1, 1));
+        let errors : Vec<_> = report.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["line 3: lat, lon is null", "line 4: latitude out of range [-90, 90]",
+                            "line 5: record of 5 characters, expected 237"]);
+        assert_eq!(out, table.replacen(&record(41.441, -71.5023, -1, -1), &record(41.441, -71.5023, 476, 34), 1));
+
+        let mut out = vec![];
+        let report = OriginAnnotator::new().overwrite(true).fill(table.as_bytes(), &mut out).unwrap();
+        assert_eq!((report.changed, report.kept), (2, 0));
+        assert!(String::from_utf8(out).unwrap().contains(&record(-42.4483, 171.214, 162, 11)));
+
+        let mut diff = vec![];
+        let report = OriginAnnotator::new().overwrite(true).dry_run(true).fill(table.as_bytes(), &mut diff).unwrap();
+        assert_eq!(report.changed, 2);
+        assert_eq!(String::from_utf8(diff).unwrap(),
+                   format!("1c1\n< {}\n---\n> {}\n2c2\n< {}\n---\n> {}\n",
+                           record(41.441, -71.5023, -1, -1), record(41.441, -71.5023, 476, 34),
+                           record(-42.4483, 171.214, 7, 3), record(-42.4483, 171.214, 162, 11)));
+    }
+    #[test]
+    fn half_null() {
+        // Region 7 is in seismic region 1, 162 in 11
+        assert_eq!((crate::SEISMIC_REGIONS[6], crate::SEISMIC_REGIONS[161]), (1, 11));
+        let table = [record(-42.4483, 171.214, 162, -1), record(-42.4483, 171.214, -1, 11),
+                     record(-42.4483, 171.214, 7, -1), record(-42.4483, 171.214, -1, 37),
+                     record(-42.4483, 171.214, 9999, -1)].join("\n") + "\n";
+        let mut out = vec![];
+        let report = OriginAnnotator::new().fill(table.as_bytes(), &mut out).unwrap();
+        assert_eq!((report.records, report.changed, report.kept), (5, 3, 2));
+        let errors : Vec<_> = report.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["line 5: region number out of range"]);
+        assert_eq!(String::from_utf8(out).unwrap(),
+                   [record(-42.4483, 171.214, 162, 11), record(-42.4483, 171.214, 162, 11),
+                    record(-42.4483, 171.214, 7, 1), record(-42.4483, 171.214, -1, 37),
+                    record(-42.4483, 171.214, 9999, -1)].join("\n") + "\n");
+
+        let mut out = vec![];
+        let report = OriginAnnotator::new().overwrite(true).fill(table.as_bytes(), &mut out).unwrap();
+        assert_eq!((report.changed, report.kept, report.errors.len()), (5, 0, 0));
+        assert_eq!(String::from_utf8(out).unwrap(), format!("{}\n", record(-42.4483, 171.214, 162, 11)).repeat(5));
+    }
+}
@@ -0,0 +1,383 @@
+//! Add region columns to a CSV earthquake catalog
+//!
+//! Records are streamed from input to output one at a time.  Each record is
+//! copied as read, quoting and line ending included, with the geographic
+//! region number, name and seismic region number appended.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use crate::{Lookup, RegionError};
+
+/// Names of the appended columns
+pub const CSV_COLUMNS: [&str; 3] = ["fe_region", "fe_region_name", "fe_seismic_region"];
+
+/// Header names tried, ignoring case, when no latitude column is given
+const LATITUDES: [&str; 2] = ["latitude", "lat"];
+/// Header names tried, ignoring case, when no longitude column is given
+const LONGITUDES: [&str; 4] = ["longitude", "lon", "long", "lng"];
+
+/// A column of a CSV file
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum Column {
+    /// Column with this header name, ignoring case
+    Name(String),
+    /// Column number, 0-based
+    Index(usize),
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Self {
+        Column::Name(name.to_string())
+    }
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Column::Name(name) => write!(f, "{:?}", name),
+            Column::Index(i) => write!(f, "{}", i),
+        }
+    }
+}
+
+/// Error stopping a CSV file from being annotated
+#[derive(Debug)]
+pub enum CsvError {
+    /// Reading the input or writing the output failed
+    Io(io::Error),
+    /// The latitude or longitude column is not in the header
+    MissingColumn {
+        /// "latitude" or "longitude"
+        what: &'static str,
+        /// Column asked for, None if none of the usual names were found
+        column: Option<Column>,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "{}", err),
+            CsvError::MissingColumn { what, column: Some(column) } => write!(f, "no {} column {}", what, column),
+            CsvError::MissingColumn { what, column: None } => write!(f, "no {} column found in the header", what),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            CsvError::MissingColumn { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+/// Problem with the location of a single record
+#[derive(Debug,Clone,PartialEq)]
+pub enum RowProblem {
+    /// The record has no field for the latitude or longitude column
+    MissingField(&'static str),
+    /// The latitude or longitude is not a number
+    Unparsable(&'static str, String),
+    /// The location is out of range
+    Region(RegionError),
+}
+
+/// A record whose region could not be found; its region columns are left empty
+#[derive(Debug,Clone,PartialEq)]
+pub struct RowError {
+    /// Line number of the start of the record, 1-based
+    pub line: usize,
+    /// What is wrong with it
+    pub problem: RowProblem,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.problem {
+            RowProblem::MissingField(what) => write!(f, "no {} field", what),
+            RowProblem::Unparsable(what, value) => write!(f, "invalid {} {:?}", what, value),
+            RowProblem::Region(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Outcome of annotating a CSV file
+#[derive(Debug,Clone,Default,PartialEq)]
+pub struct CsvReport {
+    /// Number of data records, excluding the header
+    pub records: usize,
+    /// Records that could not be annotated
+    pub errors: Vec<RowError>,
+}
+
+/// Adds Flinn-Engdahl region columns to CSV files
+///
+/// The latitude and longitude columns are found by header name or by
+/// index; by default the first column named `latitude` or `lat` and
+/// `longitude`, `lon`, `long` or `lng` is used.  The header gains the
+/// columns in [`CSV_COLUMNS`].  Records with a missing, unparsable or out
+/// of range location are written with empty region columns and reported.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let input = "time,lat,lon,mag\n\
+///               2020-01-01,41.440971,-71.502289,2.1\n\
+///               2020-01-02,\"-42.448299\",171.214005,3.0\n\
+///               2020-01-03,95,0,1.0\n";
+///  let mut out = vec![];
+///  let report = fe::CsvAnnotator::new().annotate(input.as_bytes(), &mut out).unwrap();
+///  assert_eq!(String::from_utf8(out).unwrap(),
+///             "time,lat,lon,mag,fe_region,fe_region_name,fe_seismic_region\n\
+///              2020-01-01,41.440971,-71.502289,2.1,476,SOUTHERN NEW ENGLAND,34\n\
+///              2020-01-02,\"-42.448299\",171.214005,3.0,162,\"SOUTH ISLAND, NEW ZEALAND\",11\n\
+///              2020-01-03,95,0,1.0,,,\n");
+///  assert_eq!(report.records, 3);
+///  assert_eq!(report.errors[0].to_string(), "line 4: latitude out of range [-90, 90]");
+/// ```
+///
+#[derive(Debug,Clone)]
+pub struct CsvAnnotator {
+    latitude: Option<Column>,
+    longitude: Option<Column>,
+    delimiter: char,
+    header: bool,
+    lookup: Lookup,
+}
+
+impl Default for CsvAnnotator {
+    fn default() -> Self {
+        CsvAnnotator { latitude: None, longitude: None, delimiter: ',', header: true, lookup: Lookup::new() }
+    }
+}
+
+/// Split a record into raw fields, quotes included
+fn split_fields(record: &str, delimiter: char) -> Vec<&str> {
+    let mut fields = vec![];
+    let mut quoted = false;
+    let mut start = 0;
+    for (i, c) in record.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if c == delimiter && !quoted {
+            fields.push(&record[start .. i]);
+            start = i + c.len_utf8();
+        }
+    }
+    fields.push(&record[start ..]);
+    fields
+}
+
+/// Value of a raw field, without quotes
+fn unquote(field: &str) -> String {
+    let field = field.trim();
+    match field.strip_prefix('"').and_then(|f| f.strip_suffix('"')) {
+        Some(inner) => inner.replace("\"\"", "\""),
+        None => field.to_string(),
+    }
+}
+
+/// Quote a value if needed
+fn quote(value: &str, delimiter: char) -> String {
+    if value.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Split a record from its line ending
+fn line_ending(record: &str) -> (&str, &str) {
+    let body = record.trim_end_matches(['\n', '\r']);
+    (body, &record[body.len() ..])
+}
+
+impl CsvAnnotator {
+    /// Default annotator: comma delimited, with a header naming the columns
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the latitude column
+    pub fn latitude<C: Into<Column>>(mut self, column: C) -> Self {
+        self.latitude = Some(column.into());
+        self
+    }
+    /// Set the longitude column
+    pub fn longitude<C: Into<Column>>(mut self, column: C) -> Self {
+        self.longitude = Some(column.into());
+        self
+    }
+    /// Set the field delimiter, `,` by default
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+    /// Set whether the first record is a header, true by default
+    ///
+    /// Without a header, columns must be given by index
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+    /// Set the lookup used to find regions
+    pub fn lookup(mut self, lookup: Lookup) -> Self {
+        self.lookup = lookup;
+        self
+    }
+    /// Find a column in the header
+    fn column(&self, what: &'static str, column: &Option<Column>, defaults: &[&str], header: &[String])
+              -> Result<usize, CsvError> {
+        let found = match column {
+            Some(Column::Index(i)) => return Ok(*i),
+            Some(Column::Name(name)) => header.iter().position(|h| h.eq_ignore_ascii_case(name)),
+            None => defaults.iter().find_map(|name| header.iter().position(|h| h.eq_ignore_ascii_case(name))),
+        };
+        found.ok_or_else(|| CsvError::MissingColumn { what, column: column.clone() })
+    }
+    /// Region columns of a record, with the leading delimiter
+    fn region_fields(&self, fields: &[&str], lat: usize, lon: usize) -> Result<String, RowProblem> {
+        let value = |what, i: usize| {
+            let field = fields.get(i).ok_or(RowProblem::MissingField(what))?;
+            let value = unquote(field);
+            value.parse::<f64>().map_err(|_| RowProblem::Unparsable(what, value))
+        };
+        let (lat, lon) = (value("latitude", lat)?, value("longitude", lon)?);
+        let n = self.lookup.region_number(lat, lon).map_err(RowProblem::Region)?;
+        let name = self.lookup.region(lat, lon).map_err(RowProblem::Region)?;
+        let d = self.delimiter;
+        Ok(format!("{}{}{}{}{}{}", d, n, d, quote(name, d), d, crate::SEISMIC_REGIONS[n-1]))
+    }
+    /// Annotate CSV from `input`, writing it to `output`
+    ///
+    /// Stops only if reading or writing fails or the columns are not in
+    /// the header; records that cannot be annotated are listed in the report
+    pub fn annotate<R: BufRead, W: Write>(&self, mut input: R, mut output: W) -> Result<CsvReport, CsvError> {
+        let d = self.delimiter;
+        let mut report = CsvReport::default();
+        let mut columns = None;
+        let mut record = String::new();
+        let mut line = 0;
+        loop {
+            // Read a whole record, which may span lines inside quotes
+            record.clear();
+            let start = line + 1;
+            loop {
+                let n = input.read_line(&mut record)?;
+                line += 1;
+                if n == 0 || record.matches('"').count().is_multiple_of(2) {
+                    break;
+                }
+            }
+            if record.is_empty() {
+                break;
+            }
+            let (body, ending) = line_ending(&record);
+            if body.trim().is_empty() {
+                output.write_all(record.as_bytes())?;
+                continue;
+            }
+            // A byte order mark, as written by spreadsheets, is kept in the
+            // output but not taken as part of the first field
+            let text = if columns.is_none() { body.strip_prefix('\u{feff}').unwrap_or(body) } else { body };
+            let fields = split_fields(text, d);
+            let (lat, lon) = match columns {
+                Some(columns) => columns,
+                None => {
+                    let header : Vec<_> = if self.header { fields.iter().map(|f| unquote(f)).collect() } else { vec![] };
+                    let c = (self.column("latitude", &self.latitude, &LATITUDES, &header)?,
+                             self.column("longitude", &self.longitude, &LONGITUDES, &header)?);
+                    columns = Some(c);
+                    if self.header {
+                        let names : Vec<_> = CSV_COLUMNS.iter().map(|c| quote(c, d)).collect();
+                        write!(output, "{}{}{}{}", body, d, names.join(&d.to_string()), ending)?;
+                        continue;
+                    }
+                    c
+                },
+            };
+            report.records += 1;
+            let extra = self.region_fields(&fields, lat, lon).unwrap_or_else(|problem| {
+                report.errors.push(RowError { line: start, problem });
+                d.to_string().repeat(CSV_COLUMNS.len())
+            });
+            write!(output, "{}{}{}", body, extra, ending)?;
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotate(fe: CsvAnnotator, input: &str) -> (String, CsvReport) {
+        let mut out = vec![];
+        let report = fe.annotate(input.as_bytes(), &mut out).unwrap();
+        (String::from_utf8(out).unwrap(), report)
+    }
+
+    #[test]
+    fn columns() {
+        let input = "\"Event ID\";Depth;LAT;LON\r\n\"a;b\";10;41.440971;-71.502289\r\n\r\n\"multi\nline\";5;-42.448299;171.214005\r\n";
+        let (out, report) = annotate(CsvAnnotator::new().delimiter(';'), input);
+        assert_eq!(out, "\"Event ID\";Depth;LAT;LON;fe_region;fe_region_name;fe_seismic_region\r\n\
+                         \"a;b\";10;41.440971;-71.502289;476;SOUTHERN NEW ENGLAND;34\r\n\r\n\
+                         \"multi\nline\";5;-42.448299;171.214005;162;SOUTH ISLAND, NEW ZEALAND;11\r\n");
+        assert_eq!(report, CsvReport { records: 2, errors: vec![] });
+
+        let fe = CsvAnnotator::new().header(false).latitude(1).longitude(0);
+        let (out, _) = annotate(fe, "-71.502289,41.440971");
+        assert_eq!(out, "-71.502289,41.440971,476,SOUTHERN NEW ENGLAND,34");
+
+        let (out, _) = annotate(CsvAnnotator::new().latitude("y").longitude("x"), "x,y\n0.5,0.5\n");
+        assert_eq!(out, "x,y,fe_region,fe_region_name,fe_seismic_region\n\
+                         0.5,0.5,561,OFF S. COAST OF NORTHWEST AFRICA,37\n");
+    }
+    #[test]
+    fn byte_order_mark() {
+        let (out, report) = annotate(CsvAnnotator::new(), "\u{feff}Latitude,Longitude\r\n0.5,0.5\r\n");
+        assert_eq!(out, "\u{feff}Latitude,Longitude,fe_region,fe_region_name,fe_seismic_region\r\n\
+                         0.5,0.5,561,OFF S. COAST OF NORTHWEST AFRICA,37\r\n");
+        assert_eq!(report.errors, vec![]);
+        let (out, _) = annotate(CsvAnnotator::new(), "\u{feff}\"lat\",lon\n0.5,0.5\n");
+        assert!(out.ends_with(",561,OFF S. COAST OF NORTHWEST AFRICA,37\n"));
+
+        let fe = CsvAnnotator::new().header(false).latitude(0).longitude(1);
+        let (out, report) = annotate(fe, "\u{feff}0.5,0.5\n");
+        assert_eq!(out, "\u{feff}0.5,0.5,561,OFF S. COAST OF NORTHWEST AFRICA,37\n");
+        assert_eq!(report.errors, vec![]);
+    }
+    #[test]
+    fn errors() {
+        let input = "lat,lon\n1,x\n\n95,0\n2\n0,0\n";
+        let (out, report) = annotate(CsvAnnotator::new(), input);
+        assert_eq!(out.lines().nth(1), Some("1,x,,,"));
+        assert_eq!(out.lines().last(), Some("0,0,561,OFF S. COAST OF NORTHWEST AFRICA,37"));
+        assert_eq!(report.records, 4);
+        let errors : Vec<_> = report.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["line 2: invalid longitude \"x\"",
+                            "line 4: latitude out of range [-90, 90]",
+                            "line 5: no longitude field"]);
+
+        let err = CsvAnnotator::new().annotate(&b"a,lon\n"[..], vec![]).unwrap_err();
+        assert_eq!(err.to_string(), "no latitude column found in the header");
+        let err = CsvAnnotator::new().latitude("y").annotate(&b"a,lon\n"[..], vec![]).unwrap_err();
+        assert_eq!(err.to_string(), "no latitude column \"y\"");
+        assert!(CsvAnnotator::new().header(false).annotate(&b"1,2\n"[..], vec![]).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Parsing and validation of the regionalization data files
+//!
+//! Shared between the build script, which turns `data/*.asc` into the
+//! embedded tables, and the library.  Everything here works on the file
+//! contents as strings and depends on nothing else in the crate.
+
+use std::fmt;
+
+/// Quadrant section files, in the order of the quadrant index
+pub const SECTS: [&str; 4] = ["nesect.asc", "nwsect.asc", "sesect.asc", "swsect.asc"];
+
+/// Largest region number that fits in the packed tables
+pub const MAX_REGION: usize = (1 << 10) - 1;
+
+/// Problem with the contents of a data file
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct DataError {
+    /// File name
+    pub file: String,
+    /// Line number, 1-based, or 0 for the file as a whole
+    pub line: usize,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl DataError {
+    fn new(file: &str, line: usize, message: impl Into<String>) -> Self {
+        DataError { file: file.to_string(), line, message: message.into() }
+    }
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}: {}", self.file, self.message)
+        } else {
+            write!(f, "{}:{}: {}", self.file, self.line, self.message)
+        }
+    }
+}
+
+impl std::error::Error for DataError {}
+
+/// Whitespace separated integers with their line numbers
+fn integers<'a>(file: &'a str, data: &'a str) -> impl Iterator<Item = Result<(usize, usize), DataError>> + 'a {
+    data.lines().enumerate().flat_map(move |(i, line)| {
+        line.split_whitespace().map(move |item| {
+            item.parse().map(|v| (i + 1, v))
+                .map_err(|_| DataError::new(file, i + 1, format!("expected an integer, found {:?}", item)))
+        })
+    })
+}
+
+/// Parse a list of names, one per line, e.g. names.asc
+pub fn parse_names(file: &str, data: &str) -> Result<Vec<String>, DataError> {
+    let mut out = vec![];
+    for (i, line) in data.lines().enumerate() {
+        let name = line.trim_end();
+        if name.trim().is_empty() {
+            return Err(DataError::new(file, i + 1, "empty name"));
+        }
+        out.push(name.to_string());
+    }
+    if out.is_empty() {
+        return Err(DataError::new(file, 0, "no names"));
+    }
+    Ok(out)
+}
+
+/// Parse the quadrant index, quadsidx.asc
+///
+/// Output is the "tier" and number of segments.  The tier is the onset,
+/// 1-based, into the latitude tiers and the number of segments is the
+/// number of segments in that latitude tier.
+///
+/// The file contains the number of segments in each tier, organized by
+/// quadrant.  For the 1995 version, there are 364 tiers, 91 per quadrant.
+/// Each tier represents a latitude, starting at 0 and working towards the
+/// pole by 1 degree, [0..90] inclusive range.
+///
+pub fn parse_quadsidx(file: &str, data: &str) -> Result<Vec<(usize,usize)>, DataError> {
+    let mut carry = 1;
+    let mut out = vec![];
+    for item in integers(file, data) {
+        let (line, v) = item?;
+        if v == 0 {
+            return Err(DataError::new(file, line, "tier without segments"));
+        }
+        out.push( (carry, v) );
+        carry += v;
+    }
+    if out.is_empty() || out.len() % 4 != 0 {
+        return Err(DataError::new(file, 0, format!("{} tiers is not a multiple of 4 quadrants", out.len())));
+    }
+    Ok(out)
+}
+
+/// Parse a quadrant section file, e.g. nesect.asc
+///
+/// Data within the file is organized as (longitude, region number) pairs
+pub fn parse_sect(file: &str, data: &str) -> Result<Vec<(usize,usize)>, DataError> {
+    let mut items = integers(file, data);
+    let mut out = vec![];
+    while let Some(a) = items.next() {
+        let (line, lon) = a?;
+        let (_, region) = items.next()
+            .ok_or_else(|| DataError::new(file, line, "longitude without a region number"))??;
+        if lon >= 180 {
+            return Err(DataError::new(file, line, format!("segment longitude {} out of range [0, 180)", lon)));
+        }
+        if region == 0 || region > MAX_REGION {
+            return Err(DataError::new(file, line, format!("region number {} out of range [1, {}]", region, MAX_REGION)));
+        }
+        out.push( (lon, region) );
+    }
+    Ok(out)
+}
+
+/// Join the quadrant sections into latitude tiers, checking them against the quadrant index
+///
+/// Within each tier the longitude starts at 0 and is always increasing and
+/// every region number refers to one of `regions` names
+pub fn latitude_tiers(llindx: &[(usize,usize)], sects: &[(&str, Vec<(usize,usize)>)], regions: usize)
+                      -> Result<Vec<(usize,usize)>, DataError> {
+    let per_quad = llindx.len() / sects.len();
+    let mut out = vec![];
+    for (q, (file, sect)) in sects.iter().enumerate() {
+        let tiers = &llindx[q * per_quad .. (q + 1) * per_quad];
+        let n : usize = tiers.iter().map(|t| t.1).sum();
+        if n != sect.len() {
+            return Err(DataError::new(file, 0, format!("{} segments, quadrant index expects {}", sect.len(), n)));
+        }
+        let mut k = 0;
+        for (lt, &(_, nbrbdy)) in tiers.iter().enumerate() {
+            let segs = &sect[k .. k + nbrbdy];
+            if segs[0].0 != 0 {
+                return Err(DataError::new(file, 0, format!("tier {} does not start at longitude 0", lt)));
+            }
+            if segs.windows(2).any(|w| w[0].0 >= w[1].0) {
+                return Err(DataError::new(file, 0, format!("tier {} longitudes are not increasing", lt)));
+            }
+            if let Some(s) = segs.iter().find(|s| s.1 > regions) {
+                return Err(DataError::new(file, 0, format!("tier {} region {} beyond the {} names", lt, s.1, regions)));
+            }
+            k += nbrbdy;
+        }
+        out.extend_from_slice(sect);
+    }
+    Ok(out)
+}
+
+/// Parse the seismic region number of each geographic region, seisreg.asc
+#[allow(dead_code)] // build script only
+pub fn parse_seismic_regions(file: &str, data: &str, regions: usize, seismic: usize) -> Result<Vec<usize>, DataError> {
+    let mut out = vec![];
+    for item in integers(file, data) {
+        let (line, v) = item?;
+        if v == 0 || v > seismic {
+            return Err(DataError::new(file, line, format!("seismic region {} out of range [1, {}]", v, seismic)));
+        }
+        out.push(v);
+    }
+    if out.len() != regions {
+        return Err(DataError::new(file, 0, format!("{} seismic regions for {} geographic regions", out.len(), regions)));
+    }
+    Ok(out)
+}
+
+/// Check a list of names has one entry per region
+#[allow(dead_code)] // build script only
+pub fn check_count(file: &str, names: &[String], regions: usize) -> Result<(), DataError> {
+    if names.len() != regions {
+        return Err(DataError::new(file, 0, format!("{} names for {} regions", names.len(), regions)));
+    }
+    Ok(())
+}
+
+/// Latitude tiers in the compact form used for lookups
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Compact {
+    /// Index of the first segment of each tier, followed by the number of segments
+    pub onsets: Vec<u16>,
+    /// Longitude at which each segment starts
+    pub lons: Vec<u8>,
+    /// Region numbers, packed
+    pub regions: Vec<u32>,
+}
+
+/// Tiers per quadrant, latitude 0 through 90
+pub const TIERS_PER_QUADRANT: usize = 91;
+
+/// Convert checked latitude tiers, see [`latitude_tiers`], to compact form
+pub fn compact(llindx: &[(usize,usize)], lattiers: &[(usize,usize)]) -> Result<Compact, DataError> {
+    if llindx.len() != 4 * TIERS_PER_QUADRANT {
+        return Err(DataError::new("quadsidx.asc", 0,
+                                  format!("{} tiers, expected {} per quadrant", llindx.len(), TIERS_PER_QUADRANT)));
+    }
+    if lattiers.len() > u16::MAX as usize {
+        return Err(DataError::new("quadsidx.asc", 0, format!("{} segments is too many", lattiers.len())));
+    }
+    let mut onsets : Vec<_> = llindx.iter().map(|t| (t.0 - 1) as u16).collect();
+    onsets.push(lattiers.len() as u16);
+    let lons = lattiers.iter().map(|s| s.0 as u8).collect();
+    let regions : Vec<_> = lattiers.iter().map(|s| s.1).collect();
+    Ok(Compact { onsets, lons, regions: pack_regions(&regions) })
+}
+
+/// Pack region numbers, 10 bits each, three to a word
+pub fn pack_regions(regions: &[usize]) -> Vec<u32> {
+    regions.chunks(3)
+        .map(|c| c.iter().enumerate().fold(0, |w, (k, &r)| w | (r as u32) << (10 * k)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors() {
+        let e = parse_sect("x.asc", "0 1\n 5 x").unwrap_err();
+        assert_eq!(e.to_string(), "x.asc:2: expected an integer, found \"x\"");
+        let e = parse_sect("x.asc", "0 1 5").unwrap_err();
+        assert_eq!(e.line, 1);
+        assert!(parse_sect("x.asc", "180 1").is_err());
+        assert!(parse_sect("x.asc", "0 1024").is_err());
+        assert!(parse_quadsidx("q.asc", "1 2 3").is_err());
+        assert!(parse_quadsidx("q.asc", "1 2 0 4").is_err());
+        assert!(parse_names("n.asc", "A\n\nB").is_err());
+        assert!(parse_seismic_regions("s.asc", "1 2 3", 3, 2).is_err());
+        assert!(parse_seismic_regions("s.asc", "1 2", 3, 2).is_err());
+        assert!(check_count("n.asc", &["A".to_string()], 2).is_err());
+    }
+    #[test]
+    fn tiers() {
+        let llindx = parse_quadsidx("q.asc", "1 2 1 1").unwrap();
+        assert_eq!(llindx, [(1,1), (2,2), (4,1), (5,1)]);
+        let sect = |s: &str| parse_sect("s.asc", s).unwrap();
+        let sects = [("a", sect("0 1")), ("b", sect("0 2 10 3")), ("c", sect("0 1")), ("d", sect("0 4"))];
+        assert_eq!(latitude_tiers(&llindx, &sects, 4).unwrap().len(), 5);
+        assert!(latitude_tiers(&llindx, &sects, 3).is_err());
+        let sects = [("a", sect("0 1")), ("b", sect("0 2 0 3")), ("c", sect("0 1")), ("d", sect("0 4"))];
+        assert!(latitude_tiers(&llindx, &sects, 4).is_err());
+        let sects = [("a", sect("0 1")), ("b", sect("0 2")), ("c", sect("0 1")), ("d", sect("0 4"))];
+        assert!(latitude_tiers(&llindx, &sects, 4).is_err());
+        assert_eq!(pack_regions(&[1, 2, 3, 4]), [1 | 2 << 10 | 3 << 20, 4]);
+    }
+}
@@ -0,0 +1,88 @@
+//! Dense 1 x 1 degree lookup table built from the latitude tiers
+//!
+//! Lookups truncate toward zero within each quadrant, so the cells either
+//! side of the equator and prime meridian share truncated indices but not
+//! regions.  The table therefore mirrors the tiers: 4 quadrants x 91 tiers
+//! (0 to 90) x 181 longitudes (0 to 180), one `u16` region number each,
+//! rather than a single 360 x 181 grid.  At 131 KB it is behind the
+//! `dense-grid` feature for size constrained targets.
+
+use crate::tiers::Tiers;
+
+/// Tiers per quadrant, latitude 0 through 90
+const TIERS: usize = 91;
+/// Longitudes per tier, 0 through 180
+const LONS: usize = 181;
+/// Number of entries in a dense grid
+pub(crate) const GRID_LEN: usize = 4 * TIERS * LONS;
+
+/// Build the dense grid from latitude tiers
+///
+/// Each tier is walked once, segment by segment, as in the original
+/// lookup where a segment covers longitudes from its onset up to the
+/// onset of the next.
+pub(crate) const fn dense_grid(tiers: &Tiers<'_>) -> [u16; GRID_LEN] {
+    let mut grid = [0u16; GRID_LEN];
+    let mut rec = 0;
+    while rec < 4 * TIERS {
+        let (mut seg, end) = tiers.segments(rec);
+        let mut ln = 0;
+        while ln < LONS {
+            while seg + 1 < end && tiers.lon(seg + 1) <= ln {
+                seg += 1;
+            }
+            grid[rec * LONS + ln] = tiers.region(seg) as u16;
+            ln += 1;
+        }
+        rec += 1;
+    }
+    grid
+}
+
+/// Dense grid for the 1995 revision
+pub(crate) static GRID_1995: [u16; GRID_LEN] = dense_grid(&crate::tiers::TIERS_1995);
+
+/// Index into a dense grid of a checked location, lon in [-180, 180]
+pub(crate) fn grid_index(lat: f64, lon: f64, quadid: &[usize]) -> usize {
+    let quadon = match (lat >= 0.0, lon >= 0.0) {
+        (true,  true)  => quadid[3],
+        (true,  false) => quadid[2],
+        (false, true)  => quadid[1],
+        (false, false) => quadid[0],
+    };
+    let lt = lat.abs() as usize;
+    let ln = lon.abs() as usize;
+    (quadon - 1 + lt) * LONS + ln
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Antimeridian, LongitudePolicy};
+
+    #[test]
+    fn agrees_with_tier_scan() {
+        // Every truncated cell of every quadrant, on the grid lines and
+        // within the cells, including the degenerate tiers at the poles
+        // and longitude 180
+        let quadid = crate::quadids();
+        let steps = [0.0, 0.5];
+        for &(slat, slon) in &[(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)] {
+            for lt in 0 ..= 90 {
+                for ln in 0 ..= 180 {
+                    for &dlat in &steps {
+                        for &dlon in &steps {
+                            let lat = slat * (lt as f64 + dlat).min(90.0);
+                            let lon = slon * (ln as f64 + dlon).min(180.0);
+                            let scan = crate::namnum(lat, lon, LongitudePolicy::Reject, Antimeridian::AsGiven,
+                                                     &quadid, &crate::tiers::TIERS_1995).unwrap();
+                            let dense = GRID_1995[grid_index(lat, lon, &quadid)] as usize;
+                            assert_eq!(scan, dense, "{} {}", lat, lon);
+                        }
+                    }
+                }
+            }
+        }
+        assert!(GRID_1995.iter().all(|&n| n >= 1 && n as usize <= crate::NAMES.len()));
+    }
+}
@@ -0,0 +1,141 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::num::NonZeroU16;
+use core::str::FromStr;
+
+use crate::RegionError;
+
+/// Flinn_Engdahl Geographic Region
+///
+/// Region numbers are 1-based, [1, 757], and are guaranteed to be valid
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  use std::convert::TryFrom;
+///  let region = fe::geographic_region(-42.448299, 171.214005).unwrap();
+///  assert_eq!(region.number(), 162);
+///  assert_eq!(region.name(), "SOUTH ISLAND, NEW ZEALAND");
+///  assert_eq!(region.seismic_region(), 11);
+///  assert_eq!(region, fe::GeographicRegion::try_from(162).unwrap());
+///  assert_eq!(region, "SOUTH ISLAND, NEW ZEALAND".parse().unwrap());
+/// ```
+///
+#[derive(Debug,Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub struct GeographicRegion(NonZeroU16);
+
+impl GeographicRegion {
+    /// Region number [1, 757]
+    pub fn number(&self) -> usize {
+        self.0.get() as usize
+    }
+    /// Region name
+    pub fn name(&self) -> &'static str {
+        crate::NAMES[self.number()-1]
+    }
+    /// Seismic region number [1, 50] containing this region
+    pub fn seismic_region(&self) -> usize {
+        crate::SEISMIC_REGIONS[self.number()-1]
+    }
+    /// Iterate over all geographic regions in numerical order
+    pub fn all() -> impl Iterator<Item = GeographicRegion> {
+        (1 ..= crate::NAMES.len()).map(|n| GeographicRegion::try_from(n).unwrap())
+    }
+}
+
+impl TryFrom<usize> for GeographicRegion {
+    type Error = RegionError;
+    fn try_from(n: usize) -> Result<Self, Self::Error> {
+        if n > crate::NAMES.len() {
+            return Err(RegionError::BadRegionNumber);
+        }
+        NonZeroU16::new(n as u16)
+            .map(GeographicRegion)
+            .ok_or(RegionError::BadRegionNumber)
+    }
+}
+
+impl From<GeographicRegion> for usize {
+    fn from(region: GeographicRegion) -> usize {
+        region.number()
+    }
+}
+
+impl fmt::Display for GeographicRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for GeographicRegion {
+    type Err = RegionError;
+    /// Parse from the canonical region name, e.g. "CENTRAL ALASKA"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        crate::NAMES.iter()
+            .position(|name| *name == s)
+            .map(|i| GeographicRegion::try_from(i+1).unwrap())
+            .ok_or(RegionError::UnknownRegionName)
+    }
+}
+
+/// Get the Flinn_Engdahl geographic region from a location at (`lat`,`lon`)
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let region = fe::geographic_region(41.440971, -71.502289).unwrap();
+///  assert_eq!(region.number(), 476);
+///  assert_eq!(region.to_string(), "SOUTHERN NEW ENGLAND");
+/// ```
+///
+/// # Arguments
+///   - lat - Latitude  [-90, 90]
+///   - lon - Longitude [-360, 360]
+///
+/// # Returns
+///   - Flinn_Engdahl Geographic Region
+///
+pub fn geographic_region(lat: f64, lon: f64) -> Result<GeographicRegion, RegionError> {
+    crate::Lookup::new().geographic_region(lat, lon)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::GeographicRegion;
+    use crate::RegionError;
+
+    #[test]
+    fn try_from_range() {
+        assert_eq!(GeographicRegion::try_from(0), Err(RegionError::BadRegionNumber));
+        assert_eq!(GeographicRegion::try_from(758), Err(RegionError::BadRegionNumber));
+        assert_eq!(GeographicRegion::try_from(70000), Err(RegionError::BadRegionNumber));
+        assert_eq!(GeographicRegion::try_from(1).unwrap().name(), "CENTRAL ALASKA");
+        assert_eq!(GeographicRegion::try_from(757).unwrap().name(),
+                   "GALAPAGOS TRIPLE JUNCTION REGION");
+    }
+    #[test]
+    fn names_round_trip() {
+        assert_eq!(GeographicRegion::all().count(), 757);
+        for region in GeographicRegion::all() {
+            let other : GeographicRegion = region.name().parse().unwrap();
+            assert_eq!(region, other);
+            assert_eq!(region.to_string(), region.name());
+        }
+        assert_eq!("NOT A REGION".parse::<GeographicRegion>(), Err(RegionError::UnknownRegionName));
+    }
+    #[test]
+    fn ordering() {
+        let regions : std::collections::BTreeSet<_> = [729, 1, 561, 1].iter()
+            .map(|&n| GeographicRegion::try_from(n).unwrap())
+            .collect();
+        let numbers : Vec<usize> = regions.iter().map(|r| r.number()).collect();
+        assert_eq!(numbers, [1, 561, 729]);
+    }
+    #[test]
+    fn lookup() {
+        let region = crate::geographic_region(-90., 0.).unwrap();
+        assert_eq!(region.number(), 729);
+        assert_eq!(region.seismic_region(), 50);
+        assert_eq!(crate::geographic_region(91., 0.), Err(RegionError::BadLatitude));
+    }
+}
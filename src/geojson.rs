@@ -0,0 +1,122 @@
+use std::fmt::Write;
+
+use crate::{Polygon, RegionError, Regionalization};
+use crate::cells::CellGrid;
+
+/// Escape a string for use in JSON
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            c if (c as u32) < 0x20 => { let _ = write!(out, "\\u{:04x}", c as u32); }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Write a ring as [[lon, lat], ...]
+fn ring_json(out: &mut String, ring: &[(f64, f64)]) {
+    out.push('[');
+    for (i, (lat, lon)) in ring.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "[{},{}]", lon, lat);
+    }
+    out.push(']');
+}
+
+fn feature_json(out: &mut String, region: usize, polys: &[Polygon]) {
+    let seis = crate::SEISMIC_REGIONS[region-1];
+    let _ = write!(out, "{{\"type\":\"Feature\",\"properties\":{{\"number\":{},\"name\":\"{}\",\
+                         \"seismic_region\":{},\"seismic_region_name\":\"{}\"}},",
+                   region, escape(crate::NAMES[region-1]),
+                   seis, escape(crate::SEISMIC_NAMES[seis-1]));
+    out.push_str("\"geometry\":{\"type\":\"MultiPolygon\",\"coordinates\":[");
+    for (i, p) in polys.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push('[');
+        ring_json(out, &p.exterior);
+        for h in &p.holes {
+            out.push(',');
+            ring_json(out, h);
+        }
+        out.push(']');
+    }
+    out.push_str("]}}");
+}
+
+fn collection_json(grid: &CellGrid, regions: &[usize]) -> String {
+    let mut out = String::from("{\"type\":\"FeatureCollection\",\"features\":[");
+    for (i, &r) in regions.iter().enumerate() {
+        if i > 0 {
+            out.push_str(",\n");
+        }
+        feature_json(&mut out, r, &crate::polygon::grid_polygons(grid, r));
+    }
+    out.push_str("]}\n");
+    out
+}
+
+/// Outline of a geographic region as a GeoJSON FeatureCollection
+///
+/// The collection holds a single `Feature` with a `MultiPolygon` geometry
+/// and properties `number`, `name`, `seismic_region` and `seismic_region_name`.
+/// Coordinates are [longitude, latitude]; polygons are cut at the antimeridian.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let json = fe::region_geojson(476).unwrap();
+///  assert!(json.starts_with("{\"type\":\"FeatureCollection\""));
+///  assert!(json.contains("\"name\":\"SOUTHERN NEW ENGLAND\""));
+/// ```
+///
+/// # Arguments
+///   - region - Geographic Region Number [1, 757]
+///
+pub fn region_geojson(region: usize) -> Result<String, RegionError> {
+    if Regionalization::fe1995().name(region).is_none() {
+        return Err(RegionError::BadRegionNumber);
+    }
+    Ok(collection_json(&Regionalization::fe1995().cell_grid(), &[region]))
+}
+
+/// Outline of every geographic region as a GeoJSON FeatureCollection
+///
+/// One `Feature` per region, in region number order, see [`region_geojson`]
+///
+pub fn world_geojson() -> String {
+    let regions : Vec<usize> = (1 ..= crate::NAMES.len()).collect();
+    collection_json(&Regionalization::fe1995().cell_grid(), &regions)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn escaping() {
+        assert_eq!(super::escape("D'ENTRECASTEAUX"), "D'ENTRECASTEAUX");
+        assert_eq!(super::escape("a\"b\\c\n"), "a\\\"b\\\\c\\u000a");
+    }
+    #[test]
+    fn world() {
+        let json = crate::world_geojson();
+        assert_eq!(json.matches("\"type\":\"Feature\"").count(), 757);
+        assert!(json.contains("\"number\":729,\"name\":\"ANTARCTICA\",\"seismic_region\":50"));
+        assert!(json.contains("[-180,-90]"));
+        // Balanced brackets
+        assert_eq!(json.matches('[').count(), json.matches(']').count());
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+    }
+    #[test]
+    fn single_region() {
+        let json = crate::region_geojson(162).unwrap();
+        assert_eq!(json.matches("\"type\":\"Feature\"").count(), 1);
+        assert!(json.contains("\"seismic_region_name\":\"NEW ZEALAND REGION\""));
+        assert_eq!(crate::region_geojson(0), Err(crate::RegionError::BadRegionNumber));
+    }
+}
@@ -0,0 +1,142 @@
+//! Spherical geometry on the 1 x 1 degree grid
+
+use crate::Cell;
+
+/// Mean Earth radius, km
+pub(crate) const EARTH_RADIUS: f64 = 6371.0;
+
+/// Wrap a longitude difference into [-180, 180)
+pub(crate) fn wrap(dlon: f64) -> f64 {
+    (dlon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Great circle distance in km between two points, haversine formula
+pub(crate) fn distance_km(lat0: f64, lon0: f64, lat1: f64, lon1: f64) -> f64 {
+    let (p0, p1) = (lat0.to_radians(), lat1.to_radians());
+    let dp = p1 - p0;
+    let dl = wrap(lon1 - lon0).to_radians();
+    let a = (dp / 2.0).sin().powi(2) + p0.cos() * p1.cos() * (dl / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+}
+
+/// Point reached travelling `dist` km from (`lat`, `lon`) along the azimuth
+/// with sine `sin` and cosine `cos`
+///
+/// Returned longitude is in [-180, 180); due north or south (`sin` of 0)
+/// the longitude is kept exactly
+pub(crate) fn destination(lat: f64, lon: f64, dist: f64, sin: f64, cos: f64) -> (f64, f64) {
+    let d = dist / EARTH_RADIUS;
+    let p = lat.to_radians();
+    let p1 = (p.sin() * d.cos() + p.cos() * d.sin() * cos).clamp(-1.0, 1.0).asin();
+    let dl = (sin * d.sin() * p.cos()).atan2(d.cos() - p.sin() * p1.sin());
+    (p1.to_degrees(), wrap(lon + dl.to_degrees()))
+}
+
+/// Initial azimuth in degrees [0, 360) clockwise from north from point 0 to point 1
+pub(crate) fn azimuth(lat0: f64, lon0: f64, lat1: f64, lon1: f64) -> f64 {
+    let (p0, p1) = (lat0.to_radians(), lat1.to_radians());
+    let dl = wrap(lon1 - lon0).to_radians();
+    let y = dl.sin() * p1.cos();
+    let x = p0.cos() * p1.sin() - p0.sin() * p1.cos() * dl.cos();
+    y.atan2(x).to_degrees().rem_euclid(360.0)
+}
+
+/// Cells within a window of `dlat` and `dlon` degrees around (`lat`, `lon`)
+///
+/// The window is one cell wider than needed on each side and wraps across
+/// the antimeridian; cells may repeat when the window spans all longitudes.
+/// Spans are clamped to [0, 180] degrees.
+pub(crate) fn window(lat: f64, lon: f64, dlat: f64, dlon: f64) -> impl Iterator<Item = Cell> {
+    let (dlat, dlon) = (dlat.clamp(0.0, 180.0), dlon.clamp(0.0, 180.0));
+    let lat0 = ((lat - dlat).floor() as i16 - 1).max(-90);
+    let lat1 = ((lat + dlat).floor() as i16).min(89);
+    let nlon = (2.0 * dlon).ceil().min(358.0) as i16 + 2;
+    let lon0 = (lon - dlon).floor() as i16 - 1;
+    (lat0 ..= lat1).flat_map(move |ilat| {
+        (0 ..= nlon).map(move |k| {
+            let ilon = (lon0 + k + 180).rem_euclid(360) - 180;
+            Cell::new(ilat, ilon).unwrap()
+        })
+    })
+}
+
+/// Latitude and longitude spans in degrees covering `km` around latitude `lat`
+pub(crate) fn span(lat: f64, km: f64) -> (f64, f64) {
+    let d = (km / EARTH_RADIUS).to_degrees();
+    // Longitude window grows towards the poles
+    let c = (lat.abs() + d).min(90.0).to_radians().cos();
+    let dlon = if c > 1e-6 { (d / c).min(180.0) } else { 180.0 };
+    (d, dlon)
+}
+
+/// Point of `cell` nearest to (`lat`, `lon`) and its distance in km
+pub(crate) fn nearest_in_cell(lat: f64, lon: f64, cell: &Cell) -> ((f64, f64), f64) {
+    let (s, n, w) = (cell.south(), cell.north(), cell.west());
+    let dw = wrap(lon - w);
+    if (0.0 ..= 1.0).contains(&dw) {
+        // Within the longitude range, straight along the meridian
+        let p = (lat.clamp(s, n), lon);
+        return (p, distance_km(lat, lon, p.0, p.1));
+    }
+    // Nearest point on each bounding meridian, clamped to the cell
+    let on_meridian = |edge: f64| {
+        let dl = wrap(lon - edge).to_radians();
+        let lat_c = if dl.cos() > 0.0 {
+            (lat.to_radians().tan() / dl.cos()).atan().to_degrees()
+        } else {
+            90.0_f64.copysign(lat)
+        };
+        let p = (lat_c.clamp(s, n), edge);
+        (p, distance_km(lat, lon, p.0, p.1))
+    };
+    let (a, b) = (on_meridian(w), on_meridian(w + 1.0));
+    if a.1 <= b.1 { a } else { b }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let deg = EARTH_RADIUS * 1.0_f64.to_radians();
+        assert!((distance_km(0., 0., 1., 0.) - deg).abs() < 1e-9);
+        assert!((distance_km(0., 179.5, 0., -179.5) - deg).abs() < 1e-9);
+        assert!((azimuth(0., 0., 1., 0.) - 0.0).abs() < 1e-9);
+        assert!((azimuth(0., 0., 0., 1.) - 90.0).abs() < 1e-9);
+        assert!((azimuth(0., 179.5, 0., -179.5) - 90.0).abs() < 1e-9);
+        assert!((azimuth(0., 0., -1., 0.) - 180.0).abs() < 1e-9);
+    }
+    #[test]
+    fn destinations() {
+        let deg = EARTH_RADIUS * 1.0_f64.to_radians();
+        let (lat, lon) = destination(0., 0., deg, 0.0, 1.0);
+        assert!((lat - 1.0).abs() < 1e-9 && lon.abs() < 1e-9);
+        let (lat, lon) = destination(0., 179.5, deg, 1.0, 0.0);
+        assert!(lat.abs() < 1e-9 && (lon + 179.5).abs() < 1e-9);
+        let (sin, cos) = 57.0_f64.to_radians().sin_cos();
+        let (lat, lon) = destination(10., 20., 1234.5, sin, cos);
+        assert!((distance_km(10., 20., lat, lon) - 1234.5).abs() < 1e-6);
+        assert!((azimuth(10., 20., lat, lon) - 57.0).abs() < 1e-9);
+        // Due south across the antimeridian, the longitude is kept exactly
+        assert_eq!(destination(-15.5, 180., 50.0, 0.0, -1.0).1, -180.0);
+    }
+    #[test]
+    fn nearest() {
+        let cell = Cell::new(10, 20).unwrap();
+        let ((lat, lon), d) = nearest_in_cell(10.5, 20.5, &cell);
+        assert_eq!((lat, lon, d), (10.5, 20.5, 0.0));
+        let ((lat, lon), _) = nearest_in_cell(9.0, 20.5, &cell);
+        assert_eq!((lat, lon), (10.0, 20.5));
+        let ((lat, lon), _) = nearest_in_cell(10.5, 19.0, &cell);
+        assert_eq!(lon, 20.0);
+        assert!(lat > 10.5 && lat < 10.6);
+        let ((lat, lon), _) = nearest_in_cell(5.0, 25.0, &cell);
+        assert_eq!((lat, lon), (10.0, 21.0));
+        // Across the antimeridian
+        let cell = Cell::new(0, -180).unwrap();
+        let ((_, lon), d) = nearest_in_cell(0.5, 179.9, &cell);
+        assert_eq!(lon, -180.0);
+        assert!(d < 12.0);
+    }
+}
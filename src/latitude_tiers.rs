@@ -1,5960 +0,0 @@
-const LAT_TIERS: [(usize,usize); 5958] = [
- (0, 561),
- (9, 565),
- (14, 566),
- (18, 567),
- (30, 568),
- (31, 569),
- (35, 570),
- (41, 571),
- (44, 420),
- (65, 421),
- (70, 422),
- (78, 420),
- (92, 705),
- (97, 706),
- (104, 707),
- (105, 301),
- (108, 261),
- (119, 265),
- (125, 266),
- (127, 267),
- (130, 196),
- (141, 198),
- (145, 614),
- (165, 618),
- (0, 561),
- (9, 563),
- (11, 565),
- (13, 566),
- (18, 567),
- (30, 568),
- (35, 570),
- (41, 571),
- (46, 420),
- (64, 421),
- (69, 420),
- (70, 422),
- (78, 420),
- (92, 705),
- (97, 706),
- (103, 707),
- (105, 301),
- (108, 261),
- (119, 262),
- (120, 265),
- (125, 266),
- (127, 267),
- (130, 196),
- (141, 198),
- (145, 614),
- (165, 618),
- (0, 561),
- (9, 562),
- (16, 566),
- (18, 567),
- (31, 568),
- (35, 570),
- (41, 571),
- (47, 420),
- (62, 421),
- (68, 420),
- (70, 422),
- (75, 423),
- (80, 420),
- (90, 705),
- (96, 706),
- (101, 707),
- (105, 301),
- (111, 261),
- (118, 262),
- (125, 263),
- (126, 266),
- (128, 267),
- (130, 196),
- (141, 198),
- (145, 614),
- (165, 618),
- (0, 561),
- (9, 562),
- (15, 564),
- (17, 566),
- (19, 567),
- (31, 568),
- (34, 570),
- (42, 571),
- (47, 419),
- (48, 420),
- (60, 421),
- (68, 420),
- (70, 422),
- (75, 423),
- (80, 420),
- (90, 705),
- (96, 706),
- (101, 707),
- (104, 301),
- (113, 261),
- (118, 262),
- (125, 263),
- (128, 264),
- (131, 209),
- (142, 614),
- (165, 615),
- (171, 618),
- (0, 752),
- (1, 753),
- (3, 755),
- (10, 562),
- (15, 564),
- (19, 567),
- (20, 564),
- (23, 567),
- (28, 557),
- (35, 570),
- (36, 558),
- (43, 571),
- (47, 419),
- (49, 420),
- (58, 421),
- (68, 420),
- (70, 422),
- (75, 423),
- (80, 420),
- (90, 705),
- (95, 706),
- (100, 707),
- (104, 301),
- (114, 261),
- (119, 258),
- (121, 262),
- (125, 263),
- (128, 264),
- (131, 209),
- (142, 614),
- (165, 615),
- (171, 618),
- (0, 752),
- (1, 753),
- (3, 755),
- (10, 562),
- (15, 564),
- (27, 557),
- (36, 558),
- (45, 571),
- (47, 419),
- (49, 420),
- (56, 421),
- (66, 420),
- (70, 422),
- (75, 423),
- (79, 424),
- (83, 420),
- (90, 705),
- (94, 706),
- (100, 707),
- (104, 301),
- (115, 261),
- (119, 258),
- (121, 262),
- (123, 259),
- (127, 248),
- (128, 260),
- (131, 209),
- (142, 614),
- (165, 615),
- (0, 752),
- (1, 753),
- (3, 755),
- (11, 562),
- (15, 564),
- (26, 557),
- (35, 558),
- (47, 419),
- (50, 420),
- (55, 421),
- (64, 420),
- (70, 422),
- (75, 423),
- (79, 424),
- (83, 319),
- (90, 704),
- (98, 706),
- (99, 707),
- (103, 708),
- (104, 301),
- (116, 261),
- (119, 253),
- (120, 258),
- (121, 259),
- (127, 248),
- (128, 260),
- (131, 209),
- (142, 614),
- (165, 615),
- (0, 752),
- (1, 753),
- (3, 755),
- (12, 562),
- (15, 564),
- (25, 557),
- (34, 558),
- (47, 419),
- (50, 420),
- (55, 421),
- (63, 420),
- (70, 422),
- (75, 423),
- (79, 424),
- (83, 319),
- (90, 704),
- (98, 707),
- (101, 708),
- (104, 301),
- (116, 261),
- (118, 253),
- (121, 259),
- (127, 248),
- (128, 260),
- (131, 209),
- (142, 614),
- (162, 615),
- (0, 753),
- (3, 755),
- (12, 556),
- (19, 564),
- (24, 557),
- (34, 558),
- (46, 560),
- (47, 419),
- (51, 420),
- (54, 421),
- (61, 417),
- (71, 418),
- (76, 314),
- (79, 424),
- (82, 319),
- (90, 704),
- (98, 707),
- (101, 708),
- (104, 736),
- (107, 301),
- (117, 252),
- (118, 253),
- (121, 259),
- (127, 248),
- (128, 260),
- (131, 209),
- (142, 614),
- (160, 615),
- (0, 753),
- (3, 755),
- (12, 556),
- (21, 564),
- (23, 557),
- (34, 558),
- (44, 560),
- (47, 419),
- (51, 420),
- (54, 421),
- (61, 417),
- (71, 418),
- (76, 314),
- (80, 424),
- (82, 319),
- (90, 704),
- (98, 707),
- (100, 708),
- (104, 736),
- (108, 301),
- (117, 252),
- (119, 253),
- (122, 257),
- (124, 259),
- (127, 248),
- (128, 260),
- (131, 209),
- (142, 614),
- (159, 615),
- (0, 753),
- (4, 755),
- (12, 556),
- (22, 564),
- (23, 557),
- (35, 558),
- (43, 560),
- (47, 419),
- (52, 420),
- (54, 421),
- (59, 417),
- (71, 418),
- (75, 314),
- (80, 319),
- (90, 703),
- (98, 707),
- (100, 708),
- (103, 735),
- (105, 736),
- (110, 301),
- (117, 248),
- (118, 252),
- (120, 253),
- (121, 254),
- (123, 255),
- (124, 256),
- (126, 248),
- (128, 260),
- (131, 209),
- (142, 210),
- (146, 614),
- (159, 615),
- (161, 616),
- (164, 617),
- (167, 615),
- (0, 751),
- (2, 753),
- (4, 755),
- (12, 556),
- (23, 557),
- (35, 558),
- (44, 559),
- (47, 415),
- (50, 419),
- (52, 416),
- (57, 740),
- (59, 417),
- (71, 418),
- (75, 314),
- (80, 319),
- (90, 703),
- (98, 707),
- (100, 708),
- (102, 733),
- (103, 735),
- (106, 736),
- (110, 301),
- (117, 248),
- (119, 252),
- (121, 254),
- (123, 255),
- (124, 256),
- (125, 251),
- (126, 248),
- (128, 260),
- (131, 209),
- (142, 210),
- (148, 614),
- (159, 615),
- (161, 616),
- (164, 617),
- (167, 615),
- (0, 751),
- (2, 754),
- (4, 755),
- (12, 556),
- (22, 557),
- (36, 558),
- (43, 555),
- (45, 559),
- (47, 415),
- (52, 416),
- (57, 740),
- (60, 417),
- (71, 418),
- (74, 314),
- (81, 319),
- (90, 703),
- (98, 707),
- (100, 733),
- (103, 735),
- (107, 736),
- (110, 301),
- (117, 248),
- (120, 250),
- (122, 249),
- (124, 251),
- (126, 248),
- (128, 260),
- (131, 209),
- (141, 210),
- (148, 215),
- (150, 611),
- (159, 615),
- (161, 616),
- (164, 617),
- (167, 615),
- (0, 751),
- (1, 754),
- (4, 755),
- (7, 754),
- (12, 556),
- (22, 557),
- (36, 558),
- (43, 555),
- (48, 415),
- (52, 416),
- (57, 740),
- (60, 417),
- (73, 314),
- (81, 319),
- (90, 703),
- (97, 298),
- (99, 733),
- (103, 735),
- (108, 736),
- (110, 301),
- (117, 248),
- (120, 250),
- (122, 249),
- (125, 248),
- (128, 260),
- (131, 241),
- (137, 214),
- (141, 210),
- (144, 216),
- (146, 210),
- (148, 215),
- (150, 611),
- (159, 615),
- (0, 754),
- (12, 556),
- (22, 557),
- (36, 558),
- (42, 555),
- (48, 351),
- (51, 415),
- (53, 740),
- (61, 417),
- (73, 314),
- (81, 319),
- (90, 703),
- (97, 298),
- (99, 733),
- (105, 734),
- (107, 736),
- (110, 301),
- (117, 248),
- (119, 249),
- (125, 248),
- (128, 260),
- (131, 241),
- (137, 214),
- (141, 215),
- (144, 216),
- (147, 215),
- (150, 611),
- (159, 615),
- (0, 745),
- (3, 754),
- (12, 556),
- (23, 557),
- (37, 558),
- (41, 554),
- (42, 555),
- (48, 351),
- (53, 740),
- (61, 417),
- (73, 314),
- (81, 319),
- (94, 298),
- (99, 733),
- (106, 734),
- (107, 736),
- (110, 301),
- (117, 248),
- (119, 249),
- (122, 248),
- (126, 260),
- (131, 241),
- (137, 214),
- (141, 215),
- (145, 216),
- (147, 215),
- (150, 611),
- (159, 615),
- (0, 745),
- (4, 754),
- (12, 556),
- (24, 557),
- (37, 558),
- (40, 554),
- (42, 555),
- (48, 351),
- (56, 740),
- (61, 417),
- (73, 314),
- (83, 319),
- (94, 298),
- (99, 733),
- (105, 734),
- (107, 736),
- (110, 301),
- (117, 248),
- (119, 249),
- (123, 248),
- (126, 260),
- (131, 241),
- (137, 214),
- (141, 215),
- (145, 216),
- (147, 215),
- (150, 611),
- (159, 615),
- (0, 745),
- (4, 754),
- (12, 556),
- (24, 557),
- (38, 558),
- (39, 554),
- (41, 555),
- (48, 351),
- (57, 740),
- (62, 417),
- (72, 314),
- (84, 319),
- (94, 296),
- (98, 733),
- (105, 734),
- (106, 736),
- (107, 737),
- (110, 301),
- (117, 248),
- (120, 249),
- (123, 248),
- (126, 260),
- (131, 241),
- (137, 214),
- (141, 215),
- (145, 216),
- (147, 215),
- (150, 611),
- (0, 745),
- (4, 754),
- (12, 556),
- (24, 557),
- (39, 554),
- (41, 555),
- (48, 351),
- (58, 740),
- (62, 417),
- (72, 314),
- (85, 319),
- (93, 296),
- (98, 733),
- (101, 734),
- (105, 736),
- (107, 737),
- (108, 300),
- (112, 301),
- (117, 248),
- (120, 249),
- (123, 248),
- (126, 260),
- (131, 241),
- (137, 214),
- (141, 215),
- (144, 216),
- (147, 215),
- (150, 611),
- (0, 745),
- (3, 551),
- (5, 754),
- (12, 556),
- (24, 557),
- (38, 554),
- (40, 555),
- (48, 351),
- (58, 740),
- (63, 417),
- (72, 314),
- (87, 319),
- (93, 296),
- (98, 733),
- (101, 734),
- (104, 736),
- (106, 737),
- (108, 300),
- (112, 301),
- (117, 248),
- (126, 260),
- (131, 241),
- (137, 214),
- (141, 215),
- (144, 216),
- (146, 215),
- (150, 611),
- (0, 745),
- (1, 551),
- (8, 754),
- (12, 556),
- (25, 557),
- (38, 554),
- (39, 555),
- (45, 351),
- (59, 740),
- (63, 417),
- (70, 314),
- (87, 319),
- (92, 296),
- (100, 734),
- (105, 736),
- (107, 737),
- (108, 664),
- (112, 301),
- (117, 248),
- (126, 260),
- (131, 241),
- (137, 214),
- (141, 215),
- (144, 216),
- (146, 215),
- (150, 611),
- (0, 551),
- (9, 754),
- (12, 556),
- (25, 557),
- (38, 554),
- (39, 555),
- (45, 351),
- (60, 740),
- (64, 417),
- (69, 314),
- (89, 316),
- (91, 319),
- (92, 295),
- (93, 296),
- (99, 297),
- (102, 734),
- (103, 736),
- (108, 664),
- (112, 242),
- (117, 243),
- (123, 247),
- (126, 241),
- (137, 214),
- (141, 215),
- (150, 611),
- (0, 551),
- (10, 754),
- (12, 556),
- (25, 553),
- (35, 557),
- (37, 554),
- (38, 555),
- (45, 351),
- (60, 740),
- (65, 356),
- (68, 314),
- (88, 315),
- (89, 316),
- (92, 315),
- (93, 294),
- (94, 296),
- (98, 297),
- (102, 318),
- (105, 736),
- (107, 664),
- (112, 242),
- (117, 243),
- (120, 244),
- (121, 243),
- (123, 247),
- (126, 241),
- (137, 213),
- (145, 611),
- (0, 551),
- (12, 552),
- (25, 553),
- (36, 554),
- (38, 555),
- (45, 351),
- (60, 355),
- (61, 356),
- (67, 710),
- (68, 712),
- (69, 314),
- (88, 315),
- (89, 316),
- (91, 315),
- (93, 294),
- (95, 296),
- (97, 297),
- (100, 318),
- (106, 664),
- (112, 242),
- (118, 243),
- (120, 244),
- (122, 243),
- (123, 246),
- (126, 239),
- (132, 241),
- (137, 213),
- (145, 611),
- (0, 551),
- (10, 552),
- (25, 553),
- (36, 554),
- (37, 555),
- (45, 351),
- (57, 355),
- (61, 356),
- (66, 710),
- (68, 712),
- (71, 308),
- (88, 315),
- (89, 316),
- (91, 315),
- (93, 294),
- (95, 296),
- (97, 297),
- (99, 318),
- (105, 664),
- (114, 242),
- (119, 243),
- (120, 244),
- (122, 243),
- (123, 246),
- (126, 238),
- (127, 239),
- (132, 241),
- (137, 213),
- (145, 611),
- (177, 612),
- (0, 551),
- (10, 552),
- (25, 553),
- (35, 554),
- (36, 555),
- (44, 351),
- (52, 352),
- (55, 351),
- (57, 353),
- (62, 354),
- (65, 710),
- (70, 712),
- (71, 308),
- (88, 315),
- (93, 317),
- (94, 294),
- (96, 296),
- (97, 297),
- (99, 318),
- (104, 664),
- (116, 242),
- (120, 243),
- (121, 244),
- (122, 243),
- (123, 245),
- (125, 246),
- (126, 238),
- (129, 239),
- (132, 240),
- (137, 213),
- (145, 611),
- (177, 612),
- (0, 551),
- (10, 552),
- (25, 553),
- (34, 554),
- (36, 555),
- (44, 351),
- (52, 352),
- (53, 353),
- (62, 354),
- (65, 710),
- (69, 712),
- (71, 308),
- (84, 309),
- (88, 315),
- (90, 317),
- (95, 294),
- (96, 296),
- (98, 297),
- (99, 318),
- (102, 307),
- (103, 318),
- (104, 664),
- (117, 242),
- (121, 243),
- (123, 245),
- (126, 238),
- (130, 239),
- (132, 240),
- (137, 212),
- (145, 611),
- (177, 612),
- (0, 551),
- (10, 552),
- (25, 553),
- (34, 554),
- (35, 555),
- (44, 351),
- (50, 352),
- (52, 353),
- (63, 354),
- (65, 710),
- (69, 712),
- (72, 308),
- (81, 309),
- (85, 310),
- (88, 311),
- (89, 312),
- (92, 313),
- (93, 317),
- (95, 294),
- (98, 297),
- (99, 318),
- (101, 307),
- (103, 318),
- (105, 664),
- (118, 242),
- (122, 243),
- (123, 245),
- (126, 234),
- (127, 238),
- (131, 239),
- (132, 240),
- (137, 212),
- (145, 611),
- (177, 612),
- (0, 396),
- (10, 552),
- (25, 553),
- (35, 555),
- (44, 351),
- (49, 352),
- (51, 353),
- (62, 354),
- (65, 710),
- (71, 712),
- (73, 308),
- (80, 309),
- (82, 310),
- (86, 306),
- (92, 313),
- (98, 297),
- (99, 318),
- (100, 307),
- (106, 664),
- (108, 307),
- (109, 664),
- (122, 666),
- (126, 234),
- (128, 238),
- (131, 239),
- (132, 240),
- (137, 212),
- (145, 611),
- (177, 612),
- (0, 396),
- (10, 552),
- (25, 553),
- (35, 555),
- (44, 375),
- (47, 351),
- (49, 352),
- (50, 353),
- (61, 354),
- (65, 710),
- (72, 712),
- (74, 308),
- (80, 309),
- (81, 310),
- (84, 306),
- (94, 313),
- (97, 306),
- (99, 307),
- (109, 664),
- (123, 666),
- (126, 234),
- (129, 238),
- (131, 239),
- (132, 237),
- (137, 211),
- (144, 611),
- (177, 612),
- (0, 396),
- (9, 397),
- (10, 401),
- (25, 553),
- (34, 373),
- (37, 374),
- (43, 375),
- (47, 346),
- (49, 347),
- (50, 348),
- (61, 350),
- (65, 709),
- (66, 710),
- (73, 712),
- (75, 308),
- (79, 305),
- (81, 306),
- (99, 307),
- (109, 664),
- (123, 666),
- (126, 234),
- (129, 235),
- (132, 237),
- (137, 211),
- (144, 611),
- (177, 612),
- (0, 396),
- (9, 397),
- (10, 401),
- (18, 400),
- (19, 401),
- (25, 553),
- (34, 373),
- (37, 374),
- (41, 375),
- (47, 346),
- (48, 347),
- (50, 348),
- (61, 350),
- (65, 709),
- (68, 710),
- (74, 712),
- (75, 308),
- (78, 305),
- (80, 306),
- (99, 307),
- (110, 664),
- (122, 666),
- (126, 234),
- (129, 235),
- (132, 237),
- (137, 211),
- (144, 611),
- (177, 612),
- (0, 396),
- (8, 397),
- (11, 401),
- (16, 400),
- (19, 401),
- (25, 371),
- (34, 373),
- (37, 374),
- (39, 375),
- (46, 346),
- (48, 347),
- (50, 348),
- (61, 350),
- (65, 709),
- (69, 710),
- (74, 711),
- (75, 303),
- (78, 304),
- (80, 306),
- (96, 325),
- (97, 306),
- (98, 307),
- (108, 664),
- (122, 666),
- (126, 234),
- (128, 235),
- (132, 236),
- (135, 237),
- (137, 211),
- (144, 611),
- (0, 396),
- (8, 397),
- (12, 400),
- (25, 371),
- (35, 374),
- (39, 375),
- (45, 346),
- (47, 347),
- (50, 348),
- (61, 349),
- (65, 709),
- (70, 710),
- (74, 711),
- (75, 302),
- (76, 303),
- (77, 302),
- (78, 304),
- (80, 306),
- (90, 325),
- (102, 307),
- (103, 322),
- (106, 664),
- (121, 665),
- (125, 231),
- (129, 235),
- (132, 236),
- (135, 233),
- (137, 230),
- (138, 211),
- (141, 229),
- (148, 611),
- (0, 396),
- (8, 397),
- (12, 400),
- (23, 370),
- (27, 371),
- (31, 372),
- (35, 374),
- (41, 375),
- (45, 346),
- (46, 347),
- (50, 348),
- (61, 349),
- (65, 709),
- (71, 710),
- (74, 711),
- (75, 302),
- (78, 304),
- (80, 306),
- (90, 325),
- (102, 322),
- (107, 664),
- (121, 665),
- (125, 231),
- (130, 232),
- (133, 233),
- (136, 232),
- (137, 230),
- (140, 228),
- (141, 229),
- (148, 611),
- (0, 396),
- (8, 397),
- (12, 400),
- (23, 370),
- (27, 369),
- (28, 371),
- (31, 372),
- (35, 374),
- (41, 375),
- (45, 346),
- (47, 347),
- (50, 348),
- (61, 342),
- (64, 349),
- (65, 718),
- (71, 710),
- (73, 720),
- (75, 302),
- (79, 304),
- (81, 321),
- (82, 306),
- (90, 325),
- (103, 322),
- (109, 664),
- (121, 665),
- (125, 231),
- (130, 660),
- (132, 232),
- (137, 227),
- (139, 230),
- (140, 228),
- (142, 229),
- (148, 611),
- (0, 396),
- (8, 397),
- (12, 400),
- (14, 398),
- (16, 400),
- (21, 368),
- (25, 369),
- (29, 366),
- (36, 374),
- (41, 375),
- (44, 346),
- (46, 345),
- (49, 347),
- (50, 348),
- (59, 341),
- (62, 340),
- (63, 342),
- (65, 718),
- (71, 717),
- (73, 720),
- (75, 324),
- (79, 321),
- (91, 325),
- (103, 322),
- (105, 323),
- (107, 322),
- (109, 664),
- (123, 665),
- (125, 231),
- (130, 660),
- (136, 226),
- (137, 227),
- (140, 228),
- (142, 229),
- (148, 611),
- (0, 387),
- (9, 397),
- (11, 398),
- (16, 399),
- (21, 368),
- (25, 369),
- (27, 366),
- (44, 343),
- (45, 345),
- (49, 338),
- (53, 341),
- (56, 348),
- (57, 341),
- (60, 340),
- (64, 342),
- (65, 717),
- (72, 715),
- (74, 719),
- (76, 321),
- (91, 325),
- (102, 322),
- (104, 323),
- (111, 658),
- (124, 231),
- (130, 660),
- (136, 226),
- (139, 227),
- (141, 228),
- (142, 229),
- (148, 611),
- (0, 377),
- (1, 386),
- (5, 387),
- (11, 398),
- (16, 390),
- (18, 399),
- (20, 364),
- (24, 365),
- (27, 366),
- (44, 343),
- (45, 344),
- (47, 345),
- (48, 344),
- (49, 338),
- (53, 340),
- (55, 341),
- (58, 340),
- (65, 714),
- (68, 715),
- (70, 717),
- (72, 715),
- (73, 719),
- (75, 321),
- (91, 325),
- (100, 322),
- (104, 323),
- (111, 658),
- (124, 659),
- (129, 660),
- (136, 223),
- (138, 226),
- (140, 227),
- (141, 228),
- (143, 229),
- (148, 611),
- (0, 377),
- (1, 386),
- (5, 387),
- (8, 388),
- (10, 389),
- (15, 390),
- (19, 392),
- (21, 364),
- (23, 365),
- (26, 366),
- (44, 344),
- (49, 338),
- (53, 340),
- (63, 339),
- (65, 714),
- (68, 715),
- (72, 716),
- (73, 719),
- (74, 321),
- (94, 322),
- (101, 323),
- (111, 658),
- (124, 659),
- (129, 660),
- (136, 223),
- (139, 226),
- (140, 227),
- (142, 228),
- (143, 229),
- (148, 611),
- (0, 377),
- (1, 386),
- (5, 387),
- (8, 388),
- (10, 389),
- (14, 390),
- (18, 382),
- (19, 391),
- (20, 392),
- (21, 364),
- (24, 365),
- (26, 366),
- (43, 367),
- (45, 337),
- (50, 338),
- (52, 340),
- (62, 339),
- (65, 714),
- (69, 715),
- (72, 716),
- (74, 320),
- (78, 321),
- (94, 322),
- (98, 323),
- (111, 658),
- (125, 659),
- (130, 660),
- (136, 223),
- (139, 226),
- (140, 227),
- (141, 228),
- (143, 229),
- (148, 611),
- (0, 377),
- (4, 387),
- (8, 380),
- (10, 389),
- (12, 390),
- (17, 382),
- (19, 391),
- (21, 383),
- (23, 363),
- (27, 366),
- (41, 367),
- (44, 362),
- (45, 337),
- (49, 338),
- (52, 340),
- (55, 336),
- (56, 339),
- (57, 340),
- (61, 339),
- (65, 713),
- (69, 716),
- (76, 320),
- (80, 321),
- (95, 322),
- (97, 323),
- (111, 658),
- (126, 659),
- (131, 660),
- (136, 223),
- (139, 224),
- (146, 225),
- (150, 611),
- (0, 378),
- (4, 387),
- (8, 380),
- (10, 381),
- (15, 382),
- (18, 383),
- (22, 359),
- (28, 360),
- (41, 362),
- (45, 337),
- (48, 338),
- (52, 336),
- (56, 339),
- (58, 340),
- (59, 339),
- (65, 713),
- (71, 716),
- (75, 330),
- (80, 320),
- (81, 332),
- (96, 322),
- (97, 323),
- (100, 334),
- (111, 658),
- (129, 659),
- (130, 657),
- (132, 661),
- (135, 660),
- (136, 223),
- (139, 224),
- (146, 225),
- (150, 611),
- (0, 538),
- (3, 379),
- (8, 380),
- (10, 381),
- (14, 382),
- (16, 383),
- (23, 359),
- (28, 360),
- (39, 362),
- (45, 337),
- (48, 338),
- (51, 336),
- (56, 339),
- (62, 336),
- (64, 339),
- (65, 713),
- (75, 330),
- (80, 331),
- (81, 332),
- (96, 334),
- (112, 658),
- (130, 657),
- (132, 661),
- (136, 223),
- (140, 224),
- (146, 221),
- (148, 222),
- (153, 611),
- (0, 538),
- (7, 545),
- (13, 382),
- (15, 383),
- (22, 358),
- (29, 360),
- (33, 361),
- (37, 362),
- (44, 357),
- (48, 338),
- (50, 336),
- (56, 339),
- (61, 336),
- (65, 713),
- (75, 329),
- (80, 331),
- (81, 332),
- (95, 334),
- (112, 658),
- (130, 657),
- (134, 661),
- (137, 223),
- (141, 224),
- (146, 221),
- (150, 222),
- (155, 611),
- (0, 538),
- (7, 545),
- (14, 383),
- (21, 358),
- (28, 357),
- (32, 361),
- (37, 357),
- (48, 338),
- (51, 336),
- (58, 339),
- (59, 336),
- (65, 713),
- (75, 329),
- (80, 331),
- (83, 332),
- (91, 334),
- (115, 658),
- (130, 657),
- (134, 661),
- (138, 223),
- (141, 224),
- (146, 221),
- (152, 222),
- (164, 16),
- (0, 538),
- (6, 544),
- (10, 545),
- (13, 546),
- (14, 383),
- (17, 549),
- (21, 358),
- (28, 357),
- (49, 336),
- (50, 338),
- (53, 336),
- (65, 713),
- (75, 329),
- (82, 331),
- (85, 332),
- (91, 334),
- (117, 658),
- (133, 657),
- (135, 661),
- (141, 662),
- (144, 663),
- (146, 220),
- (149, 221),
- (154, 222),
- (164, 16),
- (0, 538),
- (7, 544),
- (9, 543),
- (10, 546),
- (17, 549),
- (22, 358),
- (28, 357),
- (48, 336),
- (65, 713),
- (75, 329),
- (82, 331),
- (86, 332),
- (91, 334),
- (119, 658),
- (130, 657),
- (135, 661),
- (141, 662),
- (144, 663),
- (146, 220),
- (150, 221),
- (155, 222),
- (164, 16),
- (0, 538),
- (8, 543),
- (13, 546),
- (17, 547),
- (21, 549),
- (22, 357),
- (47, 336),
- (65, 713),
- (75, 329),
- (85, 331),
- (87, 332),
- (88, 334),
- (116, 658),
- (129, 657),
- (135, 661),
- (141, 662),
- (144, 663),
- (148, 220),
- (151, 221),
- (156, 222),
- (164, 16),
- (0, 538),
- (6, 543),
- (13, 547),
- (19, 548),
- (23, 357),
- (47, 336),
- (65, 713),
- (75, 329),
- (86, 331),
- (88, 333),
- (91, 334),
- (94, 333),
- (99, 334),
- (107, 333),
- (117, 657),
- (120, 658),
- (126, 657),
- (131, 656),
- (137, 661),
- (141, 662),
- (145, 663),
- (148, 220),
- (153, 221),
- (157, 222),
- (164, 16),
- (0, 533),
- (1, 538),
- (3, 541),
- (6, 543),
- (13, 547),
- (16, 548),
- (24, 724),
- (49, 336),
- (65, 713),
- (75, 329),
- (84, 326),
- (89, 333),
- (99, 334),
- (102, 333),
- (109, 327),
- (113, 333),
- (117, 328),
- (118, 657),
- (120, 658),
- (126, 657),
- (128, 656),
- (137, 661),
- (141, 662),
- (145, 663),
- (150, 220),
- (153, 221),
- (158, 222),
- (164, 16),
- (175, 6),
- (0, 533),
- (2, 534),
- (3, 540),
- (6, 543),
- (15, 548),
- (24, 724),
- (50, 336),
- (53, 724),
- (55, 335),
- (61, 336),
- (65, 713),
- (75, 329),
- (80, 326),
- (97, 333),
- (103, 327),
- (113, 328),
- (119, 657),
- (121, 658),
- (126, 657),
- (128, 656),
- (137, 661),
- (141, 662),
- (144, 663),
- (152, 220),
- (156, 217),
- (157, 218),
- (159, 219),
- (164, 16),
- (170, 5),
- (175, 6),
- (0, 533),
- (2, 534),
- (4, 540),
- (7, 543),
- (14, 548),
- (24, 724),
- (55, 335),
- (61, 336),
- (65, 713),
- (75, 329),
- (79, 326),
- (97, 333),
- (101, 326),
- (102, 327),
- (113, 328),
- (119, 657),
- (121, 658),
- (125, 657),
- (127, 656),
- (137, 661),
- (141, 662),
- (144, 663),
- (152, 220),
- (156, 217),
- (158, 218),
- (159, 219),
- (164, 16),
- (170, 5),
- (175, 6),
- (0, 533),
- (1, 534),
- (5, 540),
- (7, 543),
- (14, 548),
- (24, 724),
- (55, 335),
- (61, 336),
- (65, 713),
- (75, 329),
- (78, 326),
- (102, 327),
- (113, 328),
- (119, 657),
- (127, 656),
- (137, 661),
- (141, 662),
- (144, 663),
- (155, 217),
- (158, 218),
- (161, 219),
- (164, 4),
- (170, 5),
- (175, 6),
- (0, 534),
- (8, 543),
- (14, 537),
- (16, 548),
- (20, 724),
- (55, 335),
- (63, 326),
- (65, 713),
- (71, 326),
- (102, 327),
- (113, 328),
- (120, 656),
- (137, 661),
- (141, 662),
- (144, 663),
- (155, 217),
- (159, 218),
- (163, 219),
- (164, 4),
- (170, 5),
- (175, 3),
- (0, 534),
- (8, 542),
- (13, 536),
- (15, 537),
- (21, 724),
- (55, 335),
- (63, 326),
- (102, 327),
- (113, 328),
- (120, 656),
- (139, 663),
- (155, 217),
- (161, 218),
- (163, 219),
- (164, 4),
- (170, 3),
- (0, 534),
- (8, 542),
- (12, 536),
- (17, 537),
- (21, 724),
- (55, 335),
- (63, 326),
- (102, 327),
- (113, 328),
- (120, 656),
- (139, 663),
- (155, 217),
- (161, 218),
- (164, 4),
- (170, 3),
- (0, 534),
- (8, 542),
- (11, 536),
- (17, 537),
- (21, 724),
- (55, 335),
- (63, 326),
- (102, 327),
- (113, 328),
- (120, 656),
- (141, 663),
- (156, 217),
- (162, 218),
- (164, 3),
- (0, 534),
- (5, 535),
- (10, 536),
- (18, 537),
- (21, 724),
- (55, 335),
- (63, 326),
- (110, 328),
- (120, 656),
- (142, 671),
- (155, 663),
- (157, 217),
- (165, 3),
- (0, 534),
- (4, 535),
- (12, 536),
- (19, 537),
- (21, 724),
- (55, 335),
- (63, 326),
- (110, 328),
- (120, 656),
- (142, 671),
- (156, 663),
- (160, 217),
- (165, 671),
- (171, 3),
- (0, 534),
- (4, 535),
- (12, 536),
- (19, 721),
- (27, 723),
- (29, 724),
- (55, 335),
- (63, 725),
- (85, 726),
- (130, 671),
- (173, 3),
- (0, 642),
- (4, 535),
- (12, 536),
- (19, 721),
- (28, 723),
- (31, 724),
- (55, 335),
- (63, 725),
- (85, 726),
- (130, 671),
- (176, 3),
- (0, 642),
- (5, 535),
- (12, 536),
- (20, 721),
- (30, 723),
- (32, 724),
- (55, 335),
- (63, 725),
- (85, 726),
- (130, 671),
- (0, 642),
- (7, 535),
- (12, 536),
- (21, 721),
- (30, 723),
- (32, 724),
- (55, 335),
- (65, 725),
- (85, 726),
- (130, 671),
- (0, 642),
- (10, 646),
- (14, 536),
- (23, 721),
- (29, 723),
- (31, 724),
- (55, 335),
- (65, 725),
- (85, 726),
- (130, 671),
- (0, 642),
- (10, 646),
- (14, 536),
- (24, 721),
- (29, 723),
- (31, 724),
- (55, 335),
- (68, 725),
- (85, 726),
- (130, 671),
- (0, 642),
- (11, 646),
- (16, 536),
- (24, 721),
- (29, 723),
- (30, 724),
- (55, 335),
- (68, 725),
- (85, 726),
- (130, 671),
- (0, 642),
- (12, 646),
- (17, 536),
- (24, 721),
- (29, 723),
- (30, 724),
- (61, 335),
- (68, 725),
- (85, 726),
- (130, 671),
- (178, 670),
- (0, 642),
- (12, 646),
- (20, 536),
- (22, 721),
- (28, 723),
- (30, 724),
- (40, 647),
- (43, 724),
- (64, 335),
- (68, 725),
- (85, 726),
- (130, 671),
- (158, 670),
- (0, 642),
- (15, 646),
- (26, 721),
- (28, 723),
- (29, 722),
- (32, 724),
- (37, 647),
- (48, 724),
- (66, 725),
- (85, 726),
- (130, 671),
- (150, 670),
- (0, 642),
- (18, 646),
- (31, 647),
- (50, 648),
- (58, 724),
- (61, 649),
- (66, 725),
- (85, 726),
- (130, 670),
- (161, 669),
- (168, 670),
- (172, 669),
- (178, 670),
- (0, 642),
- (20, 647),
- (23, 646),
- (29, 647),
- (50, 648),
- (59, 649),
- (66, 725),
- (85, 726),
- (130, 670),
- (159, 669),
- (178, 670),
- (0, 642),
- (20, 647),
- (50, 648),
- (59, 649),
- (68, 650),
- (85, 726),
- (130, 655),
- (139, 670),
- (151, 669),
- (0, 640),
- (10, 642),
- (20, 647),
- (52, 648),
- (59, 649),
- (69, 650),
- (85, 726),
- (110, 653),
- (130, 655),
- (135, 668),
- (145, 669),
- (0, 640),
- (10, 642),
- (20, 647),
- (52, 648),
- (62, 649),
- (79, 650),
- (85, 653),
- (88, 726),
- (110, 653),
- (130, 655),
- (135, 668),
- (155, 669),
- (0, 640),
- (10, 643),
- (25, 647),
- (54, 648),
- (67, 649),
- (81, 650),
- (85, 653),
- (116, 655),
- (135, 668),
- (155, 669),
- (0, 640),
- (5, 643),
- (25, 647),
- (58, 648),
- (70, 649),
- (88, 653),
- (116, 655),
- (135, 668),
- (161, 669),
- (0, 640),
- (5, 643),
- (35, 647),
- (65, 648),
- (70, 649),
- (88, 653),
- (97, 652),
- (101, 653),
- (116, 655),
- (135, 667),
- (154, 668),
- (161, 634),
- (0, 640),
- (5, 643),
- (35, 647),
- (70, 649),
- (93, 652),
- (110, 654),
- (135, 667),
- (161, 634),
- (0, 640),
- (5, 643),
- (35, 645),
- (70, 649),
- (85, 652),
- (110, 654),
- (135, 667),
- (161, 634),
- (0, 641),
- (5, 643),
- (35, 645),
- (70, 649),
- (85, 652),
- (110, 654),
- (135, 633),
- (155, 634),
- (0, 641),
- (5, 643),
- (35, 645),
- (70, 649),
- (85, 652),
- (110, 654),
- (135, 633),
- (155, 634),
- (0, 641),
- (35, 645),
- (70, 651),
- (135, 633),
- (155, 634),
- (0, 641),
- (35, 644),
- (70, 651),
- (135, 633),
- (155, 634),
- (0, 641),
- (35, 644),
- (70, 651),
- (130, 633),
- (160, 634),
- (0, 641),
- (35, 644),
- (70, 651),
- (120, 633),
- (170, 634),
- (0, 641),
- (35, 644),
- (70, 651),
- (120, 633),
- (170, 634),
- (0, 641),
- (35, 644),
- (70, 651),
- (100, 633),
- (0, 633),
- (0, 633),
- (0, 633),
- (0, 561),
- (9, 407),
- (18, 406),
- (32, 402),
- (49, 528),
- (62, 113),
- (70, 103),
- (75, 106),
- (79, 105),
- (81, 104),
- (86, 696),
- (89, 697),
- (92, 696),
- (96, 695),
- (100, 757),
- (106, 693),
- (120, 611),
- (153, 620),
- (165, 611),
- (0, 561),
- (9, 407),
- (18, 406),
- (33, 402),
- (49, 528),
- (58, 529),
- (60, 528),
- (62, 113),
- (64, 101),
- (67, 113),
- (70, 103),
- (78, 106),
- (79, 105),
- (80, 104),
- (86, 696),
- (96, 695),
- (100, 757),
- (106, 693),
- (120, 611),
- (153, 620),
- (156, 622),
- (161, 620),
- (165, 611),
- (0, 561),
- (18, 402),
- (20, 406),
- (34, 402),
- (49, 528),
- (53, 531),
- (54, 530),
- (55, 528),
- (56, 530),
- (57, 529),
- (60, 528),
- (62, 113),
- (64, 101),
- (67, 103),
- (77, 102),
- (79, 83),
- (83, 76),
- (86, 696),
- (96, 695),
- (100, 757),
- (106, 693),
- (120, 611),
- (153, 620),
- (156, 622),
- (161, 620),
- (165, 611),
- (0, 561),
- (18, 402),
- (26, 406),
- (35, 402),
- (50, 528),
- (52, 531),
- (54, 530),
- (58, 529),
- (60, 528),
- (62, 113),
- (64, 101),
- (67, 103),
- (77, 102),
- (78, 83),
- (83, 76),
- (89, 693),
- (100, 757),
- (106, 693),
- (120, 611),
- (153, 620),
- (156, 622),
- (161, 620),
- (165, 611),
- (0, 752),
- (3, 750),
- (7, 749),
- (13, 561),
- (18, 402),
- (28, 406),
- (36, 402),
- (50, 531),
- (54, 530),
- (58, 529),
- (60, 528),
- (61, 101),
- (68, 103),
- (77, 102),
- (78, 83),
- (83, 76),
- (89, 693),
- (100, 757),
- (106, 693),
- (120, 611),
- (153, 620),
- (156, 622),
- (161, 620),
- (165, 611),
- (0, 752),
- (3, 750),
- (7, 749),
- (13, 561),
- (18, 402),
- (30, 406),
- (38, 402),
- (50, 531),
- (54, 530),
- (57, 529),
- (61, 101),
- (68, 103),
- (77, 102),
- (78, 83),
- (83, 76),
- (90, 693),
- (100, 757),
- (106, 693),
- (120, 611),
- (153, 620),
- (161, 621),
- (164, 620),
- (165, 611),
- (0, 752),
- (3, 750),
- (8, 749),
- (12, 748),
- (13, 561),
- (18, 402),
- (30, 406),
- (40, 402),
- (52, 531),
- (54, 530),
- (57, 529),
- (61, 101),
- (70, 99),
- (77, 102),
- (78, 83),
- (83, 76),
- (90, 693),
- (100, 757),
- (106, 693),
- (120, 611),
- (153, 620),
- (161, 621),
- (164, 620),
- (165, 611),
- (0, 752),
- (3, 750),
- (8, 749),
- (11, 748),
- (15, 561),
- (18, 402),
- (32, 406),
- (40, 402),
- (57, 529),
- (61, 101),
- (72, 99),
- (77, 82),
- (78, 81),
- (79, 83),
- (80, 81),
- (82, 83),
- (83, 77),
- (86, 76),
- (91, 693),
- (97, 63),
- (100, 730),
- (106, 693),
- (120, 611),
- (153, 620),
- (165, 611),
- (0, 752),
- (3, 750),
- (8, 747),
- (10, 748),
- (15, 561),
- (18, 402),
- (32, 406),
- (43, 402),
- (58, 529),
- (60, 97),
- (61, 101),
- (73, 99),
- (76, 96),
- (77, 82),
- (78, 81),
- (82, 80),
- (83, 78),
- (84, 77),
- (87, 76),
- (92, 693),
- (96, 63),
- (100, 730),
- (106, 693),
- (120, 611),
- (153, 620),
- (165, 611),
- (0, 752),
- (3, 750),
- (8, 747),
- (11, 748),
- (13, 747),
- (15, 561),
- (18, 402),
- (35, 406),
- (44, 402),
- (60, 97),
- (63, 101),
- (71, 100),
- (72, 101),
- (73, 99),
- (75, 96),
- (78, 81),
- (82, 80),
- (83, 78),
- (85, 77),
- (88, 76),
- (92, 693),
- (95, 63),
- (100, 730),
- (106, 63),
- (110, 693),
- (120, 611),
- (153, 620),
- (165, 611),
- (0, 752),
- (3, 751),
- (6, 750),
- (7, 745),
- (8, 747),
- (18, 402),
- (40, 403),
- (45, 402),
- (60, 98),
- (62, 97),
- (69, 101),
- (71, 100),
- (72, 101),
- (73, 99),
- (74, 96),
- (76, 79),
- (83, 78),
- (86, 77),
- (88, 76),
- (93, 63),
- (100, 730),
- (106, 63),
- (110, 693),
- (120, 611),
- (0, 751),
- (5, 745),
- (9, 747),
- (18, 402),
- (42, 403),
- (46, 402),
- (60, 95),
- (63, 94),
- (68, 97),
- (72, 96),
- (76, 79),
- (83, 75),
- (86, 74),
- (88, 76),
- (93, 63),
- (100, 730),
- (106, 63),
- (110, 693),
- (120, 611),
- (0, 751),
- (4, 745),
- (11, 746),
- (13, 747),
- (16, 746),
- (18, 402),
- (43, 403),
- (47, 402),
- (59, 95),
- (63, 94),
- (69, 97),
- (71, 96),
- (73, 94),
- (83, 75),
- (87, 74),
- (88, 76),
- (93, 68),
- (96, 63),
- (100, 730),
- (106, 63),
- (113, 693),
- (120, 611),
- (0, 751),
- (3, 745),
- (12, 746),
- (18, 402),
- (43, 403),
- (48, 402),
- (59, 95),
- (63, 94),
- (83, 75),
- (87, 72),
- (88, 73),
- (90, 71),
- (92, 68),
- (96, 67),
- (98, 65),
- (104, 730),
- (106, 63),
- (113, 693),
- (120, 611),
- (0, 751),
- (2, 745),
- (12, 746),
- (18, 402),
- (43, 403),
- (48, 402),
- (59, 95),
- (63, 94),
- (83, 75),
- (85, 72),
- (89, 70),
- (92, 69),
- (94, 68),
- (95, 67),
- (98, 65),
- (104, 730),
- (106, 63),
- (115, 693),
- (120, 611),
- (0, 745),
- (10, 744),
- (11, 745),
- (12, 744),
- (13, 746),
- (18, 402),
- (43, 403),
- (48, 402),
- (59, 92),
- (64, 94),
- (83, 72),
- (89, 70),
- (91, 62),
- (93, 69),
- (94, 66),
- (98, 65),
- (104, 64),
- (106, 63),
- (115, 693),
- (120, 611),
- (150, 612),
- (165, 619),
- (172, 611),
- (0, 745),
- (5, 744),
- (14, 746),
- (18, 402),
- (43, 403),
- (48, 402),
- (59, 92),
- (64, 94),
- (81, 731),
- (88, 93),
- (89, 70),
- (90, 62),
- (92, 61),
- (94, 60),
- (98, 58),
- (102, 65),
- (103, 64),
- (106, 63),
- (115, 693),
- (120, 611),
- (150, 612),
- (165, 619),
- (172, 611),
- (0, 745),
- (6, 744),
- (18, 402),
- (43, 403),
- (48, 402),
- (59, 92),
- (64, 91),
- (65, 90),
- (67, 89),
- (69, 88),
- (72, 87),
- (75, 86),
- (79, 731),
- (88, 93),
- (89, 62),
- (92, 61),
- (95, 60),
- (98, 59),
- (101, 58),
- (102, 56),
- (104, 64),
- (105, 54),
- (108, 53),
- (115, 693),
- (120, 611),
- (150, 612),
- (165, 619),
- (172, 611),
- (0, 745),
- (6, 744),
- (18, 402),
- (43, 403),
- (48, 402),
- (60, 92),
- (64, 91),
- (65, 90),
- (67, 89),
- (69, 88),
- (72, 87),
- (75, 86),
- (79, 731),
- (87, 84),
- (91, 527),
- (95, 525),
- (97, 523),
- (99, 59),
- (102, 57),
- (103, 56),
- (104, 55),
- (105, 54),
- (108, 53),
- (115, 693),
- (120, 611),
- (150, 612),
- (154, 613),
- (157, 612),
- (172, 611),
- (0, 745),
- (6, 744),
- (18, 402),
- (43, 403),
- (48, 402),
- (60, 92),
- (64, 91),
- (65, 90),
- (67, 89),
- (68, 402),
- (69, 88),
- (72, 87),
- (74, 85),
- (81, 731),
- (87, 84),
- (91, 527),
- (95, 525),
- (98, 523),
- (100, 57),
- (103, 524),
- (104, 55),
- (106, 54),
- (108, 53),
- (115, 693),
- (120, 611),
- (150, 612),
- (154, 613),
- (157, 612),
- (172, 611),
- (0, 745),
- (6, 744),
- (18, 402),
- (43, 403),
- (48, 402),
- (69, 88),
- (72, 87),
- (74, 85),
- (86, 84),
- (91, 527),
- (96, 525),
- (99, 523),
- (102, 524),
- (105, 55),
- (106, 54),
- (108, 53),
- (115, 693),
- (120, 611),
- (150, 612),
- (154, 613),
- (159, 612),
- (175, 611),
- (0, 745),
- (6, 744),
- (13, 743),
- (18, 402),
- (43, 403),
- (48, 402),
- (69, 515),
- (74, 85),
- (86, 84),
- (91, 526),
- (97, 525),
- (99, 523),
- (101, 524),
- (104, 523),
- (105, 52),
- (106, 51),
- (108, 53),
- (115, 693),
- (120, 611),
- (150, 612),
- (155, 613),
- (161, 612),
- (175, 611),
- (0, 551),
- (1, 745),
- (6, 744),
- (13, 743),
- (18, 402),
- (43, 403),
- (48, 402),
- (69, 515),
- (76, 85),
- (86, 526),
- (97, 525),
- (99, 523),
- (105, 52),
- (107, 51),
- (109, 47),
- (117, 693),
- (120, 611),
- (150, 612),
- (157, 613),
- (161, 612),
- (178, 611),
- (0, 551),
- (3, 745),
- (6, 744),
- (12, 743),
- (18, 402),
- (43, 403),
- (48, 402),
- (69, 515),
- (78, 85),
- (86, 526),
- (97, 523),
- (106, 52),
- (107, 49),
- (109, 48),
- (111, 47),
- (118, 693),
- (120, 611),
- (150, 612),
- (178, 611),
- (0, 551),
- (4, 745),
- (7, 744),
- (12, 743),
- (18, 402),
- (43, 403),
- (48, 402),
- (69, 513),
- (72, 515),
- (80, 514),
- (82, 526),
- (97, 523),
- (108, 49),
- (110, 48),
- (113, 47),
- (119, 693),
- (120, 611),
- (150, 612),
- (0, 551),
- (6, 744),
- (12, 743),
- (16, 394),
- (22, 402),
- (42, 403),
- (48, 402),
- (69, 513),
- (73, 515),
- (80, 514),
- (82, 526),
- (97, 518),
- (98, 522),
- (108, 49),
- (111, 48),
- (113, 47),
- (120, 611),
- (150, 612),
- (0, 551),
- (7, 744),
- (9, 743),
- (15, 394),
- (22, 402),
- (42, 403),
- (48, 402),
- (69, 513),
- (74, 515),
- (80, 514),
- (83, 526),
- (97, 520),
- (98, 518),
- (100, 522),
- (109, 49),
- (112, 48),
- (114, 47),
- (120, 611),
- (150, 612),
- (0, 551),
- (9, 743),
- (14, 394),
- (22, 402),
- (41, 403),
- (47, 402),
- (69, 513),
- (75, 515),
- (80, 514),
- (83, 526),
- (97, 520),
- (98, 519),
- (99, 518),
- (100, 522),
- (106, 521),
- (108, 50),
- (110, 49),
- (112, 48),
- (115, 47),
- (121, 611),
- (160, 612),
- (0, 396),
- (7, 395),
- (13, 394),
- (22, 402),
- (39, 403),
- (47, 402),
- (69, 513),
- (80, 514),
- (83, 526),
- (95, 520),
- (98, 519),
- (100, 518),
- (101, 522),
- (104, 521),
- (109, 50),
- (111, 49),
- (113, 48),
- (115, 47),
- (121, 611),
- (160, 612),
- (0, 396),
- (5, 395),
- (11, 394),
- (22, 402),
- (38, 403),
- (47, 402),
- (69, 513),
- (80, 514),
- (83, 508),
- (86, 526),
- (89, 504),
- (94, 503),
- (95, 500),
- (100, 518),
- (105, 521),
- (109, 50),
- (112, 49),
- (114, 48),
- (116, 47),
- (122, 611),
- (165, 612),
- (0, 396),
- (3, 395),
- (10, 394),
- (22, 402),
- (36, 403),
- (46, 402),
- (69, 513),
- (81, 510),
- (83, 508),
- (88, 505),
- (90, 504),
- (94, 503),
- (95, 500),
- (100, 498),
- (104, 518),
- (106, 521),
- (109, 50),
- (113, 49),
- (115, 48),
- (117, 47),
- (122, 611),
- (165, 612),
- (0, 396),
- (3, 395),
- (10, 393),
- (22, 402),
- (34, 403),
- (44, 402),
- (69, 513),
- (81, 509),
- (85, 507),
- (88, 505),
- (92, 504),
- (94, 503),
- (95, 500),
- (100, 498),
- (105, 518),
- (107, 517),
- (109, 516),
- (112, 46),
- (113, 49),
- (115, 48),
- (117, 47),
- (122, 611),
- (170, 612),
- (0, 396),
- (1, 395),
- (10, 393),
- (22, 402),
- (32, 403),
- (43, 402),
- (69, 513),
- (79, 511),
- (81, 509),
- (85, 507),
- (88, 505),
- (91, 504),
- (94, 503),
- (95, 500),
- (100, 498),
- (103, 496),
- (109, 495),
- (112, 46),
- (115, 45),
- (118, 38),
- (125, 611),
- (0, 396),
- (2, 395),
- (9, 384),
- (10, 393),
- (22, 402),
- (30, 403),
- (42, 402),
- (69, 513),
- (78, 511),
- (82, 509),
- (85, 507),
- (88, 505),
- (91, 502),
- (94, 501),
- (95, 500),
- (100, 498),
- (103, 496),
- (109, 495),
- (112, 42),
- (114, 44),
- (115, 43),
- (120, 38),
- (127, 611),
- (0, 396),
- (2, 395),
- (8, 384),
- (10, 393),
- (20, 404),
- (35, 403),
- (41, 402),
- (69, 513),
- (76, 512),
- (79, 511),
- (83, 509),
- (85, 507),
- (88, 505),
- (91, 502),
- (94, 501),
- (95, 499),
- (100, 497),
- (103, 496),
- (109, 495),
- (112, 42),
- (114, 44),
- (115, 43),
- (121, 38),
- (127, 611),
- (0, 396),
- (2, 385),
- (8, 384),
- (10, 739),
- (20, 404),
- (35, 403),
- (39, 402),
- (69, 513),
- (75, 512),
- (84, 506),
- (90, 502),
- (94, 501),
- (95, 499),
- (100, 497),
- (103, 496),
- (109, 495),
- (112, 42),
- (114, 40),
- (116, 39),
- (122, 38),
- (130, 611),
- (0, 387),
- (2, 385),
- (8, 384),
- (10, 739),
- (20, 404),
- (25, 405),
- (27, 404),
- (35, 403),
- (37, 402),
- (69, 513),
- (75, 512),
- (82, 506),
- (89, 486),
- (91, 484),
- (94, 501),
- (95, 499),
- (100, 497),
- (103, 496),
- (109, 495),
- (112, 42),
- (114, 41),
- (115, 40),
- (118, 39),
- (123, 38),
- (132, 611),
- (0, 377),
- (7, 376),
- (10, 739),
- (20, 404),
- (25, 405),
- (31, 404),
- (35, 403),
- (36, 402),
- (69, 513),
- (75, 493),
- (77, 492),
- (80, 491),
- (83, 490),
- (88, 488),
- (89, 487),
- (90, 485),
- (91, 483),
- (94, 482),
- (95, 480),
- (102, 479),
- (109, 478),
- (114, 41),
- (117, 40),
- (119, 39),
- (123, 38),
- (132, 611),
- (0, 377),
- (7, 376),
- (10, 739),
- (20, 404),
- (25, 405),
- (31, 404),
- (35, 402),
- (69, 513),
- (75, 493),
- (77, 492),
- (80, 491),
- (83, 490),
- (86, 489),
- (88, 488),
- (90, 485),
- (91, 483),
- (94, 482),
- (95, 480),
- (102, 479),
- (109, 478),
- (114, 37),
- (118, 40),
- (120, 36),
- (123, 35),
- (124, 34),
- (132, 611),
- (0, 377),
- (7, 376),
- (10, 402),
- (20, 404),
- (27, 405),
- (33, 404),
- (35, 402),
- (69, 513),
- (74, 494),
- (75, 493),
- (77, 492),
- (79, 491),
- (81, 471),
- (85, 469),
- (87, 467),
- (91, 483),
- (94, 482),
- (95, 480),
- (102, 479),
- (109, 478),
- (114, 37),
- (120, 36),
- (123, 35),
- (125, 34),
- (132, 611),
- (0, 377),
- (7, 376),
- (10, 402),
- (20, 404),
- (35, 402),
- (69, 513),
- (72, 472),
- (74, 494),
- (75, 473),
- (81, 471),
- (85, 469),
- (87, 467),
- (91, 481),
- (95, 465),
- (96, 463),
- (102, 479),
- (109, 478),
- (114, 37),
- (120, 36),
- (124, 35),
- (125, 34),
- (132, 611),
- (0, 377),
- (6, 376),
- (10, 402),
- (20, 404),
- (35, 402),
- (69, 476),
- (73, 472),
- (75, 473),
- (80, 471),
- (85, 469),
- (87, 467),
- (91, 465),
- (96, 463),
- (104, 460),
- (111, 478),
- (114, 37),
- (120, 36),
- (124, 35),
- (125, 34),
- (132, 611),
- (0, 378),
- (2, 377),
- (10, 402),
- (20, 404),
- (35, 402),
- (66, 477),
- (70, 476),
- (73, 472),
- (80, 470),
- (83, 468),
- (87, 467),
- (88, 466),
- (91, 465),
- (97, 463),
- (104, 460),
- (111, 457),
- (114, 33),
- (117, 32),
- (124, 31),
- (125, 30),
- (132, 611),
- (0, 378),
- (2, 377),
- (10, 402),
- (20, 403),
- (35, 402),
- (65, 452),
- (67, 477),
- (70, 475),
- (71, 474),
- (73, 472),
- (79, 470),
- (82, 468),
- (87, 466),
- (91, 464),
- (97, 462),
- (104, 460),
- (111, 457),
- (114, 33),
- (117, 32),
- (124, 31),
- (125, 30),
- (132, 611),
- (0, 538),
- (2, 539),
- (5, 402),
- (20, 403),
- (35, 402),
- (62, 452),
- (67, 475),
- (71, 474),
- (73, 472),
- (76, 470),
- (82, 468),
- (87, 466),
- (92, 464),
- (97, 462),
- (104, 460),
- (109, 459),
- (111, 458),
- (112, 457),
- (114, 33),
- (117, 32),
- (124, 31),
- (125, 30),
- (132, 611),
- (0, 538),
- (2, 539),
- (5, 402),
- (22, 403),
- (35, 402),
- (59, 452),
- (65, 451),
- (67, 475),
- (71, 447),
- (74, 470),
- (83, 468),
- (88, 466),
- (93, 464),
- (97, 462),
- (104, 456),
- (114, 33),
- (117, 32),
- (119, 28),
- (124, 31),
- (125, 30),
- (132, 21),
- (142, 17),
- (165, 16),
- (0, 538),
- (2, 539),
- (5, 402),
- (23, 403),
- (35, 402),
- (52, 455),
- (56, 402),
- (59, 452),
- (62, 453),
- (64, 451),
- (68, 475),
- (70, 447),
- (79, 441),
- (84, 468),
- (90, 466),
- (92, 464),
- (97, 461),
- (104, 456),
- (114, 33),
- (117, 29),
- (123, 28),
- (124, 27),
- (125, 26),
- (132, 21),
- (142, 17),
- (165, 16),
- (0, 538),
- (5, 402),
- (24, 403),
- (34, 402),
- (52, 455),
- (59, 454),
- (64, 451),
- (68, 448),
- (70, 447),
- (79, 441),
- (86, 468),
- (90, 464),
- (97, 461),
- (104, 456),
- (116, 33),
- (117, 29),
- (124, 27),
- (125, 26),
- (132, 21),
- (142, 17),
- (165, 16),
- (0, 538),
- (5, 402),
- (24, 403),
- (34, 402),
- (52, 446),
- (53, 455),
- (59, 454),
- (64, 448),
- (70, 447),
- (79, 441),
- (94, 464),
- (97, 461),
- (104, 456),
- (116, 33),
- (117, 29),
- (123, 25),
- (132, 21),
- (142, 17),
- (165, 16),
- (0, 538),
- (3, 402),
- (24, 403),
- (34, 402),
- (52, 446),
- (53, 455),
- (59, 454),
- (61, 450),
- (65, 448),
- (67, 447),
- (79, 441),
- (95, 439),
- (102, 438),
- (110, 24),
- (114, 23),
- (123, 25),
- (132, 21),
- (142, 17),
- (165, 16),
- (0, 533),
- (6, 402),
- (24, 403),
- (33, 402),
- (52, 446),
- (55, 455),
- (58, 449),
- (67, 447),
- (79, 441),
- (95, 439),
- (102, 438),
- (110, 24),
- (115, 23),
- (125, 25),
- (132, 21),
- (142, 17),
- (165, 16),
- (172, 7),
- (0, 533),
- (6, 532),
- (11, 402),
- (24, 403),
- (33, 402),
- (52, 446),
- (55, 455),
- (57, 449),
- (67, 443),
- (80, 441),
- (95, 439),
- (102, 438),
- (110, 24),
- (117, 23),
- (127, 25),
- (129, 22),
- (135, 21),
- (142, 17),
- (165, 16),
- (167, 9),
- (172, 7),
- (0, 533),
- (6, 532),
- (11, 402),
- (25, 403),
- (32, 738),
- (37, 402),
- (52, 446),
- (55, 445),
- (66, 443),
- (80, 441),
- (95, 439),
- (102, 438),
- (110, 24),
- (118, 23),
- (129, 22),
- (135, 21),
- (142, 17),
- (165, 16),
- (166, 9),
- (172, 7),
- (0, 533),
- (6, 532),
- (11, 402),
- (25, 403),
- (32, 738),
- (37, 402),
- (52, 446),
- (55, 445),
- (68, 443),
- (81, 441),
- (95, 439),
- (102, 438),
- (110, 24),
- (120, 23),
- (130, 22),
- (135, 21),
- (142, 17),
- (163, 10),
- (165, 9),
- (172, 7),
- (0, 533),
- (8, 532),
- (11, 402),
- (32, 738),
- (37, 402),
- (52, 446),
- (57, 445),
- (68, 443),
- (81, 441),
- (93, 439),
- (102, 438),
- (110, 24),
- (120, 23),
- (130, 19),
- (131, 22),
- (135, 21),
- (142, 17),
- (160, 12),
- (163, 10),
- (165, 9),
- (172, 3),
- (0, 534),
- (1, 533),
- (9, 402),
- (32, 738),
- (37, 402),
- (52, 446),
- (59, 445),
- (64, 443),
- (78, 440),
- (86, 441),
- (91, 439),
- (102, 438),
- (110, 24),
- (120, 23),
- (130, 19),
- (135, 20),
- (142, 15),
- (151, 17),
- (157, 12),
- (163, 10),
- (165, 9),
- (169, 3),
- (0, 534),
- (1, 533),
- (8, 402),
- (31, 738),
- (37, 402),
- (52, 446),
- (61, 445),
- (64, 443),
- (77, 440),
- (88, 441),
- (90, 439),
- (102, 438),
- (110, 24),
- (120, 23),
- (130, 19),
- (136, 20),
- (142, 15),
- (151, 13),
- (155, 12),
- (162, 11),
- (163, 3),
- (167, 8),
- (172, 3),
- (0, 534),
- (1, 533),
- (8, 402),
- (30, 738),
- (37, 402),
- (52, 446),
- (61, 445),
- (64, 443),
- (78, 440),
- (90, 439),
- (102, 438),
- (110, 24),
- (120, 23),
- (132, 19),
- (137, 20),
- (142, 15),
- (151, 13),
- (155, 12),
- (159, 11),
- (163, 3),
- (167, 8),
- (172, 3),
- (0, 534),
- (2, 533),
- (8, 402),
- (29, 738),
- (35, 402),
- (52, 446),
- (62, 445),
- (64, 443),
- (78, 440),
- (93, 439),
- (102, 438),
- (110, 24),
- (120, 23),
- (133, 19),
- (139, 20),
- (142, 15),
- (151, 13),
- (154, 12),
- (158, 11),
- (163, 3),
- (0, 533),
- (4, 402),
- (28, 738),
- (33, 402),
- (52, 446),
- (63, 445),
- (65, 443),
- (66, 442),
- (69, 443),
- (78, 440),
- (94, 439),
- (102, 438),
- (110, 24),
- (120, 23),
- (134, 19),
- (142, 15),
- (148, 14),
- (152, 2),
- (168, 3),
- (0, 533),
- (4, 402),
- (27, 738),
- (31, 402),
- (42, 680),
- (49, 444),
- (64, 445),
- (65, 442),
- (69, 443),
- (78, 440),
- (94, 679),
- (124, 18),
- (139, 19),
- (142, 2),
- (148, 14),
- (152, 2),
- (168, 3),
- (0, 642),
- (10, 637),
- (30, 402),
- (42, 680),
- (50, 444),
- (64, 442),
- (71, 443),
- (79, 440),
- (93, 679),
- (127, 18),
- (141, 2),
- (167, 3),
- (0, 642),
- (10, 637),
- (30, 402),
- (40, 680),
- (51, 444),
- (64, 682),
- (70, 442),
- (72, 443),
- (78, 442),
- (80, 440),
- (91, 679),
- (129, 18),
- (141, 1),
- (161, 676),
- (166, 3),
- (169, 674),
- (170, 3),
- (0, 642),
- (10, 637),
- (18, 638),
- (21, 637),
- (30, 402),
- (40, 680),
- (52, 444),
- (64, 682),
- (71, 442),
- (80, 679),
- (130, 18),
- (141, 1),
- (161, 676),
- (165, 673),
- (168, 674),
- (172, 3),
- (0, 642),
- (10, 637),
- (14, 638),
- (23, 637),
- (30, 402),
- (40, 680),
- (53, 444),
- (64, 682),
- (79, 679),
- (131, 18),
- (141, 1),
- (161, 676),
- (167, 673),
- (171, 671),
- (0, 642),
- (10, 637),
- (14, 638),
- (23, 637),
- (30, 402),
- (35, 636),
- (40, 680),
- (54, 444),
- (62, 682),
- (79, 679),
- (132, 677),
- (141, 676),
- (168, 673),
- (169, 671),
- (0, 642),
- (10, 637),
- (30, 402),
- (33, 636),
- (40, 680),
- (54, 444),
- (61, 682),
- (75, 679),
- (134, 677),
- (141, 676),
- (167, 673),
- (169, 670),
- (0, 642),
- (10, 637),
- (30, 636),
- (40, 680),
- (54, 444),
- (63, 682),
- (74, 679),
- (136, 677),
- (141, 676),
- (166, 673),
- (170, 672),
- (172, 670),
- (0, 642),
- (10, 637),
- (25, 636),
- (40, 680),
- (54, 444),
- (65, 682),
- (75, 679),
- (136, 677),
- (141, 676),
- (167, 672),
- (176, 670),
- (0, 639),
- (20, 636),
- (40, 680),
- (55, 681),
- (67, 682),
- (78, 679),
- (137, 677),
- (141, 676),
- (164, 672),
- (178, 670),
- (0, 639),
- (20, 636),
- (40, 680),
- (55, 681),
- (67, 682),
- (90, 679),
- (130, 675),
- (141, 676),
- (163, 672),
- (177, 670),
- (0, 639),
- (20, 636),
- (40, 680),
- (56, 681),
- (71, 682),
- (90, 679),
- (127, 675),
- (154, 676),
- (158, 675),
- (166, 672),
- (177, 670),
- (0, 639),
- (20, 636),
- (40, 680),
- (57, 681),
- (74, 682),
- (90, 679),
- (127, 675),
- (166, 669),
- (0, 640),
- (20, 636),
- (40, 680),
- (57, 681),
- (76, 682),
- (90, 679),
- (127, 675),
- (166, 669),
- (0, 640),
- (17, 636),
- (40, 680),
- (58, 681),
- (79, 678),
- (116, 679),
- (125, 675),
- (166, 669),
- (0, 640),
- (17, 636),
- (40, 680),
- (60, 681),
- (79, 678),
- (125, 675),
- (166, 669),
- (0, 640),
- (17, 636),
- (40, 680),
- (71, 681),
- (78, 678),
- (125, 634),
- (166, 669),
- (0, 640),
- (17, 636),
- (40, 680),
- (73, 678),
- (125, 634),
- (0, 640),
- (17, 636),
- (40, 680),
- (73, 678),
- (120, 634),
- (0, 640),
- (17, 636),
- (40, 680),
- (70, 678),
- (110, 634),
- (0, 641),
- (15, 635),
- (30, 636),
- (40, 680),
- (55, 635),
- (67, 678),
- (100, 634),
- (0, 641),
- (10, 635),
- (65, 678),
- (95, 634),
- (0, 641),
- (10, 635),
- (60, 678),
- (90, 634),
- (0, 641),
- (10, 635),
- (60, 678),
- (80, 634),
- (0, 641),
- (10, 635),
- (60, 634),
- (0, 641),
- (10, 633),
- (60, 634),
- (0, 641),
- (10, 633),
- (75, 634),
- (0, 641),
- (10, 633),
- (75, 634),
- (0, 633),
- (0, 633),
- (0, 633),
- (0, 409),
- (9, 565),
- (14, 566),
- (18, 567),
- (30, 568),
- (31, 569),
- (35, 570),
- (41, 571),
- (43, 425),
- (65, 421),
- (70, 422),
- (78, 425),
- (92, 273),
- (98, 274),
- (105, 275),
- (109, 261),
- (118, 265),
- (124, 269),
- (127, 267),
- (130, 196),
- (141, 198),
- (145, 199),
- (149, 190),
- (154, 191),
- (164, 632),
- (171, 618),
- (0, 409),
- (9, 565),
- (14, 566),
- (17, 567),
- (29, 572),
- (31, 569),
- (35, 570),
- (42, 574),
- (43, 425),
- (67, 421),
- (70, 422),
- (78, 425),
- (92, 273),
- (98, 274),
- (107, 275),
- (109, 261),
- (118, 268),
- (124, 269),
- (127, 267),
- (130, 196),
- (137, 197),
- (140, 196),
- (141, 198),
- (145, 199),
- (149, 190),
- (154, 191),
- (164, 632),
- (171, 618),
- (0, 409),
- (9, 565),
- (12, 566),
- (16, 567),
- (28, 572),
- (31, 569),
- (35, 573),
- (37, 570),
- (41, 574),
- (43, 425),
- (67, 421),
- (70, 422),
- (78, 425),
- (92, 273),
- (99, 274),
- (107, 275),
- (109, 261),
- (118, 268),
- (124, 270),
- (129, 272),
- (131, 196),
- (137, 201),
- (139, 197),
- (141, 200),
- (143, 198),
- (145, 199),
- (149, 190),
- (154, 191),
- (164, 632),
- (171, 618),
- (0, 409),
- (10, 565),
- (12, 566),
- (16, 567),
- (28, 572),
- (31, 569),
- (35, 573),
- (38, 570),
- (41, 574),
- (45, 425),
- (67, 421),
- (68, 426),
- (78, 425),
- (92, 273),
- (100, 274),
- (107, 275),
- (111, 261),
- (118, 268),
- (124, 270),
- (126, 271),
- (127, 272),
- (131, 196),
- (136, 201),
- (141, 202),
- (142, 200),
- (146, 203),
- (150, 190),
- (154, 191),
- (164, 632),
- (171, 618),
- (0, 409),
- (11, 566),
- (15, 567),
- (28, 572),
- (31, 573),
- (39, 570),
- (40, 574),
- (45, 425),
- (67, 421),
- (68, 426),
- (78, 425),
- (94, 273),
- (101, 274),
- (107, 275),
- (118, 268),
- (123, 280),
- (132, 196),
- (137, 201),
- (141, 202),
- (144, 200),
- (146, 207),
- (147, 203),
- (150, 192),
- (153, 190),
- (154, 193),
- (161, 191),
- (164, 632),
- (175, 623),
- (0, 409),
- (12, 575),
- (13, 567),
- (28, 572),
- (31, 573),
- (40, 574),
- (47, 425),
- (67, 421),
- (68, 426),
- (78, 425),
- (94, 273),
- (102, 274),
- (105, 276),
- (106, 277),
- (108, 275),
- (118, 268),
- (123, 280),
- (132, 204),
- (135, 196),
- (137, 205),
- (139, 201),
- (141, 202),
- (145, 207),
- (148, 192),
- (153, 190),
- (154, 193),
- (161, 191),
- (164, 632),
- (175, 623),
- (0, 409),
- (12, 575),
- (17, 567),
- (28, 572),
- (32, 573),
- (40, 574),
- (47, 425),
- (67, 421),
- (68, 426),
- (78, 425),
- (94, 273),
- (104, 276),
- (106, 277),
- (114, 278),
- (118, 279),
- (123, 280),
- (131, 281),
- (133, 204),
- (136, 196),
- (138, 205),
- (139, 201),
- (141, 202),
- (146, 207),
- (148, 192),
- (154, 193),
- (162, 191),
- (164, 632),
- (175, 623),
- (0, 409),
- (12, 575),
- (17, 567),
- (19, 575),
- (22, 567),
- (29, 572),
- (32, 573),
- (40, 574),
- (49, 425),
- (66, 429),
- (68, 426),
- (78, 425),
- (96, 273),
- (105, 277),
- (114, 278),
- (118, 279),
- (123, 280),
- (130, 281),
- (133, 204),
- (136, 208),
- (137, 205),
- (140, 201),
- (141, 202),
- (143, 206),
- (146, 207),
- (149, 192),
- (154, 193),
- (164, 183),
- (173, 632),
- (176, 623),
- (0, 409),
- (12, 575),
- (22, 567),
- (29, 572),
- (32, 573),
- (40, 574),
- (49, 425),
- (66, 429),
- (68, 426),
- (78, 425),
- (96, 273),
- (105, 282),
- (107, 277),
- (114, 283),
- (116, 285),
- (119, 286),
- (124, 289),
- (128, 290),
- (130, 281),
- (133, 208),
- (137, 205),
- (141, 206),
- (146, 207),
- (151, 194),
- (155, 193),
- (164, 183),
- (173, 632),
- (176, 623),
- (0, 409),
- (12, 575),
- (22, 567),
- (29, 572),
- (32, 576),
- (33, 573),
- (40, 574),
- (51, 425),
- (65, 429),
- (68, 426),
- (78, 425),
- (96, 273),
- (105, 282),
- (114, 284),
- (116, 285),
- (119, 287),
- (121, 288),
- (123, 289),
- (127, 290),
- (132, 208),
- (140, 205),
- (141, 206),
- (144, 595),
- (146, 207),
- (151, 194),
- (156, 193),
- (164, 183),
- (165, 184),
- (168, 183),
- (173, 632),
- (176, 623),
- (0, 409),
- (13, 575),
- (22, 567),
- (29, 576),
- (33, 577),
- (35, 573),
- (41, 574),
- (51, 425),
- (65, 429),
- (72, 425),
- (98, 273),
- (105, 282),
- (114, 284),
- (116, 291),
- (119, 287),
- (121, 288),
- (123, 289),
- (125, 290),
- (132, 208),
- (141, 594),
- (144, 595),
- (147, 207),
- (151, 194),
- (156, 195),
- (158, 193),
- (164, 183),
- (165, 184),
- (168, 183),
- (173, 180),
- (0, 409),
- (13, 575),
- (24, 576),
- (25, 567),
- (29, 576),
- (33, 577),
- (35, 573),
- (37, 581),
- (41, 574),
- (48, 583),
- (51, 425),
- (65, 429),
- (71, 425),
- (98, 273),
- (105, 282),
- (114, 284),
- (116, 291),
- (119, 292),
- (121, 293),
- (125, 290),
- (130, 591),
- (138, 208),
- (141, 594),
- (144, 595),
- (151, 194),
- (156, 195),
- (159, 193),
- (164, 183),
- (165, 184),
- (168, 183),
- (173, 180),
- (0, 409),
- (12, 575),
- (24, 576),
- (28, 567),
- (30, 576),
- (33, 577),
- (35, 581),
- (41, 574),
- (48, 583),
- (51, 425),
- (64, 429),
- (71, 425),
- (105, 588),
- (116, 291),
- (119, 292),
- (121, 293),
- (129, 591),
- (138, 593),
- (141, 594),
- (145, 595),
- (151, 194),
- (156, 195),
- (159, 193),
- (164, 183),
- (165, 184),
- (168, 183),
- (173, 180),
- (0, 409),
- (12, 575),
- (22, 576),
- (33, 577),
- (35, 581),
- (41, 574),
- (47, 583),
- (51, 425),
- (64, 429),
- (70, 425),
- (105, 588),
- (125, 590),
- (129, 591),
- (137, 593),
- (141, 594),
- (146, 595),
- (151, 194),
- (156, 195),
- (162, 185),
- (165, 186),
- (169, 185),
- (173, 181),
- (0, 409),
- (12, 575),
- (22, 576),
- (32, 581),
- (34, 577),
- (36, 581),
- (41, 582),
- (46, 583),
- (51, 425),
- (64, 429),
- (70, 425),
- (105, 588),
- (124, 590),
- (129, 591),
- (137, 593),
- (141, 594),
- (147, 595),
- (156, 195),
- (162, 185),
- (165, 186),
- (170, 185),
- (173, 181),
- (0, 409),
- (11, 575),
- (22, 576),
- (30, 581),
- (34, 577),
- (36, 581),
- (41, 582),
- (45, 583),
- (51, 425),
- (64, 429),
- (70, 425),
- (105, 588),
- (123, 590),
- (129, 591),
- (138, 593),
- (141, 594),
- (147, 595),
- (156, 596),
- (162, 185),
- (165, 186),
- (170, 185),
- (173, 181),
- (177, 182),
- (0, 409),
- (11, 575),
- (22, 576),
- (29, 580),
- (33, 581),
- (35, 577),
- (36, 581),
- (41, 582),
- (44, 583),
- (51, 425),
- (64, 429),
- (70, 425),
- (105, 588),
- (121, 590),
- (129, 591),
- (138, 594),
- (147, 595),
- (156, 596),
- (162, 185),
- (165, 186),
- (170, 185),
- (173, 181),
- (177, 182),
- (0, 409),
- (11, 578),
- (18, 575),
- (23, 576),
- (27, 580),
- (33, 581),
- (40, 582),
- (43, 583),
- (51, 425),
- (53, 427),
- (67, 429),
- (70, 425),
- (105, 588),
- (121, 590),
- (129, 591),
- (138, 594),
- (147, 595),
- (159, 597),
- (162, 187),
- (164, 185),
- (166, 186),
- (171, 185),
- (173, 181),
- (177, 182),
- (0, 409),
- (11, 578),
- (21, 579),
- (26, 580),
- (33, 581),
- (38, 582),
- (43, 583),
- (50, 425),
- (53, 427),
- (67, 429),
- (70, 425),
- (105, 588),
- (120, 590),
- (129, 591),
- (138, 594),
- (149, 605),
- (159, 597),
- (162, 187),
- (165, 185),
- (167, 186),
- (171, 185),
- (173, 181),
- (177, 182),
- (0, 409),
- (12, 578),
- (21, 579),
- (26, 580),
- (33, 581),
- (36, 582),
- (43, 583),
- (50, 425),
- (53, 427),
- (67, 429),
- (70, 425),
- (105, 588),
- (117, 590),
- (129, 591),
- (138, 594),
- (150, 605),
- (159, 597),
- (162, 187),
- (165, 185),
- (168, 186),
- (171, 185),
- (175, 171),
- (0, 409),
- (13, 578),
- (21, 579),
- (28, 580),
- (33, 581),
- (36, 582),
- (42, 583),
- (50, 425),
- (53, 427),
- (67, 429),
- (72, 425),
- (105, 588),
- (114, 590),
- (129, 591),
- (138, 594),
- (150, 605),
- (159, 597),
- (162, 187),
- (166, 188),
- (169, 186),
- (171, 185),
- (175, 171),
- (0, 409),
- (13, 578),
- (21, 579),
- (28, 580),
- (32, 581),
- (36, 582),
- (42, 583),
- (49, 425),
- (53, 427),
- (67, 429),
- (72, 425),
- (105, 588),
- (113, 590),
- (129, 591),
- (138, 594),
- (152, 605),
- (159, 597),
- (163, 187),
- (167, 188),
- (169, 189),
- (173, 185),
- (175, 171),
- (0, 409),
- (14, 578),
- (20, 579),
- (29, 584),
- (31, 581),
- (36, 582),
- (42, 583),
- (49, 425),
- (53, 427),
- (67, 429),
- (73, 425),
- (105, 589),
- (112, 590),
- (129, 591),
- (138, 594),
- (152, 605),
- (160, 597),
- (164, 187),
- (169, 189),
- (175, 171),
- (0, 409),
- (14, 578),
- (20, 579),
- (27, 584),
- (32, 581),
- (36, 582),
- (42, 583),
- (49, 425),
- (53, 427),
- (67, 429),
- (74, 425),
- (105, 589),
- (112, 590),
- (129, 591),
- (138, 594),
- (153, 605),
- (161, 597),
- (165, 187),
- (169, 189),
- (175, 171),
- (0, 409),
- (14, 578),
- (20, 579),
- (26, 584),
- (32, 581),
- (36, 582),
- (42, 583),
- (48, 425),
- (53, 427),
- (67, 429),
- (76, 425),
- (105, 589),
- (112, 590),
- (129, 591),
- (138, 594),
- (154, 605),
- (162, 597),
- (169, 189),
- (175, 171),
- (0, 409),
- (14, 578),
- (20, 584),
- (21, 579),
- (23, 584),
- (24, 579),
- (26, 584),
- (32, 581),
- (35, 582),
- (42, 583),
- (48, 425),
- (63, 428),
- (67, 741),
- (70, 429),
- (76, 425),
- (105, 589),
- (112, 590),
- (129, 591),
- (138, 594),
- (151, 604),
- (155, 605),
- (162, 597),
- (169, 189),
- (175, 171),
- (0, 409),
- (14, 578),
- (20, 584),
- (21, 579),
- (22, 584),
- (31, 586),
- (32, 581),
- (33, 587),
- (35, 425),
- (62, 428),
- (66, 741),
- (71, 429),
- (77, 425),
- (105, 589),
- (112, 590),
- (129, 592),
- (141, 594),
- (152, 604),
- (155, 605),
- (162, 597),
- (169, 606),
- (173, 189),
- (175, 171),
- (0, 409),
- (15, 578),
- (20, 584),
- (33, 587),
- (35, 425),
- (61, 428),
- (65, 741),
- (72, 429),
- (77, 425),
- (105, 589),
- (112, 590),
- (129, 592),
- (141, 594),
- (152, 604),
- (155, 605),
- (165, 606),
- (173, 176),
- (178, 177),
- (0, 409),
- (15, 578),
- (20, 584),
- (33, 587),
- (35, 425),
- (60, 428),
- (64, 741),
- (68, 425),
- (73, 429),
- (78, 425),
- (105, 589),
- (113, 590),
- (129, 592),
- (141, 594),
- (152, 604),
- (155, 605),
- (165, 606),
- (173, 176),
- (178, 177),
- (0, 409),
- (15, 587),
- (16, 584),
- (27, 585),
- (29, 584),
- (32, 587),
- (35, 425),
- (59, 428),
- (63, 425),
- (73, 429),
- (78, 425),
- (105, 589),
- (113, 590),
- (129, 592),
- (141, 601),
- (152, 604),
- (155, 605),
- (165, 606),
- (173, 176),
- (178, 177),
- (0, 409),
- (15, 587),
- (17, 584),
- (32, 587),
- (35, 425),
- (58, 428),
- (62, 425),
- (74, 429),
- (79, 434),
- (105, 589),
- (114, 590),
- (129, 592),
- (141, 601),
- (152, 604),
- (155, 605),
- (165, 607),
- (173, 176),
- (178, 177),
- (0, 409),
- (15, 587),
- (17, 584),
- (31, 587),
- (35, 425),
- (56, 428),
- (61, 425),
- (74, 429),
- (79, 434),
- (105, 589),
- (114, 590),
- (129, 600),
- (135, 592),
- (141, 601),
- (152, 604),
- (155, 605),
- (165, 607),
- (173, 176),
- (178, 177),
- (0, 409),
- (15, 587),
- (17, 584),
- (30, 587),
- (35, 425),
- (54, 428),
- (60, 425),
- (74, 429),
- (79, 434),
- (105, 589),
- (114, 590),
- (129, 600),
- (140, 592),
- (141, 601),
- (151, 603),
- (154, 605),
- (165, 607),
- (173, 176),
- (178, 179),
- (0, 409),
- (15, 587),
- (17, 584),
- (29, 587),
- (35, 425),
- (52, 428),
- (58, 425),
- (74, 429),
- (79, 434),
- (87, 425),
- (105, 589),
- (114, 590),
- (126, 599),
- (133, 600),
- (140, 592),
- (141, 601),
- (150, 603),
- (153, 605),
- (165, 607),
- (173, 176),
- (178, 179),
- (0, 409),
- (15, 587),
- (17, 584),
- (27, 587),
- (35, 425),
- (49, 428),
- (56, 425),
- (74, 429),
- (79, 434),
- (84, 435),
- (87, 425),
- (105, 589),
- (114, 590),
- (125, 599),
- (134, 600),
- (140, 592),
- (141, 601),
- (150, 603),
- (153, 605),
- (165, 607),
- (173, 160),
- (176, 176),
- (178, 179),
- (0, 409),
- (15, 587),
- (35, 425),
- (47, 428),
- (54, 425),
- (74, 429),
- (84, 435),
- (87, 425),
- (105, 598),
- (114, 590),
- (120, 599),
- (135, 600),
- (141, 602),
- (144, 601),
- (149, 603),
- (152, 605),
- (165, 607),
- (172, 158),
- (173, 159),
- (175, 160),
- (0, 409),
- (15, 587),
- (35, 425),
- (45, 428),
- (53, 425),
- (74, 429),
- (84, 435),
- (87, 425),
- (105, 598),
- (114, 599),
- (136, 600),
- (141, 602),
- (148, 601),
- (149, 603),
- (152, 605),
- (165, 607),
- (172, 158),
- (174, 159),
- (175, 160),
- (0, 409),
- (15, 587),
- (35, 425),
- (45, 428),
- (51, 425),
- (73, 429),
- (84, 435),
- (90, 425),
- (105, 598),
- (114, 599),
- (139, 600),
- (141, 603),
- (151, 605),
- (165, 607),
- (172, 158),
- (174, 159),
- (177, 160),
- (0, 409),
- (15, 587),
- (35, 425),
- (44, 428),
- (51, 425),
- (73, 429),
- (84, 435),
- (92, 425),
- (105, 598),
- (114, 599),
- (139, 600),
- (141, 603),
- (151, 605),
- (165, 607),
- (172, 158),
- (174, 159),
- (178, 160),
- (0, 409),
- (15, 587),
- (35, 425),
- (44, 428),
- (50, 425),
- (73, 429),
- (84, 435),
- (97, 425),
- (105, 598),
- (114, 599),
- (142, 608),
- (149, 610),
- (165, 607),
- (172, 158),
- (174, 159),
- (177, 160),
- (0, 409),
- (15, 587),
- (35, 425),
- (40, 428),
- (50, 425),
- (72, 429),
- (84, 435),
- (105, 598),
- (114, 437),
- (142, 608),
- (149, 610),
- (165, 161),
- (173, 163),
- (175, 159),
- (177, 160),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (72, 429),
- (84, 435),
- (105, 598),
- (114, 437),
- (142, 609),
- (150, 610),
- (160, 161),
- (171, 162),
- (174, 163),
- (175, 159),
- (176, 160),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (71, 429),
- (84, 435),
- (114, 437),
- (142, 609),
- (150, 610),
- (160, 161),
- (171, 162),
- (174, 164),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (71, 429),
- (84, 435),
- (114, 437),
- (142, 609),
- (150, 610),
- (160, 161),
- (169, 162),
- (173, 164),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 429),
- (84, 435),
- (114, 437),
- (142, 609),
- (150, 610),
- (160, 161),
- (167, 162),
- (172, 164),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 429),
- (84, 435),
- (114, 437),
- (142, 609),
- (150, 610),
- (160, 161),
- (167, 162),
- (171, 164),
- (177, 168),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 429),
- (84, 435),
- (114, 437),
- (142, 700),
- (150, 165),
- (162, 161),
- (168, 162),
- (170, 164),
- (177, 168),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 429),
- (84, 435),
- (114, 742),
- (142, 700),
- (150, 165),
- (162, 161),
- (169, 164),
- (177, 168),
- (0, 409),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 429),
- (84, 435),
- (114, 742),
- (142, 700),
- (150, 165),
- (162, 161),
- (169, 164),
- (173, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 429),
- (84, 435),
- (114, 742),
- (142, 700),
- (150, 165),
- (162, 166),
- (170, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 436),
- (80, 425),
- (96, 435),
- (114, 742),
- (142, 700),
- (150, 165),
- (162, 166),
- (170, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 431),
- (44, 432),
- (57, 425),
- (63, 433),
- (76, 436),
- (80, 425),
- (96, 435),
- (114, 742),
- (142, 700),
- (150, 165),
- (162, 166),
- (170, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (63, 433),
- (76, 436),
- (80, 425),
- (96, 435),
- (114, 742),
- (120, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (63, 433),
- (76, 436),
- (80, 425),
- (96, 435),
- (114, 742),
- (120, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (63, 433),
- (76, 436),
- (83, 425),
- (114, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (71, 436),
- (83, 425),
- (114, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (71, 436),
- (83, 425),
- (114, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 412),
- (9, 413),
- (20, 430),
- (32, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 414),
- (20, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (135, 701),
- (155, 167),
- (164, 168),
- (0, 414),
- (20, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (148, 702),
- (0, 414),
- (20, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (148, 702),
- (0, 414),
- (20, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (148, 702),
- (0, 414),
- (20, 425),
- (71, 436),
- (86, 425),
- (114, 437),
- (148, 702),
- (0, 414),
- (20, 425),
- (51, 729),
- (56, 425),
- (71, 436),
- (86, 425),
- (95, 729),
- (105, 425),
- (111, 729),
- (114, 437),
- (148, 702),
- (0, 414),
- (20, 425),
- (48, 729),
- (59, 425),
- (71, 436),
- (81, 729),
- (145, 437),
- (148, 702),
- (0, 414),
- (20, 425),
- (44, 729),
- (71, 436),
- (79, 729),
- (148, 702),
- (0, 414),
- (20, 425),
- (33, 729),
- (36, 425),
- (39, 729),
- (156, 702),
- (0, 729),
- (162, 702),
- (0, 729),
- (162, 727),
- (168, 702),
- (171, 728),
- (0, 729),
- (162, 727),
- (171, 728),
- (0, 729),
- (159, 727),
- (171, 728),
- (0, 729),
- (159, 727),
- (170, 728),
- (0, 729),
- (155, 727),
- (167, 728),
- (0, 729),
- (155, 727),
- (164, 728),
- (0, 729),
- (155, 727),
- (164, 728),
- (0, 729),
- (155, 727),
- (170, 729),
- (178, 728),
- (0, 729),
- (155, 727),
- (170, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 409),
- (9, 407),
- (18, 406),
- (31, 409),
- (45, 528),
- (62, 113),
- (70, 103),
- (75, 110),
- (76, 107),
- (80, 105),
- (81, 104),
- (86, 696),
- (89, 697),
- (92, 696),
- (96, 695),
- (100, 694),
- (107, 693),
- (120, 632),
- (153, 620),
- (165, 632),
- (168, 624),
- (176, 632),
- (0, 409),
- (9, 407),
- (18, 406),
- (27, 409),
- (43, 528),
- (62, 113),
- (69, 103),
- (73, 111),
- (75, 110),
- (76, 107),
- (80, 105),
- (81, 104),
- (86, 696),
- (89, 697),
- (92, 696),
- (96, 695),
- (100, 694),
- (107, 693),
- (120, 632),
- (153, 620),
- (165, 632),
- (168, 624),
- (176, 632),
- (0, 409),
- (9, 407),
- (18, 406),
- (20, 409),
- (38, 528),
- (62, 113),
- (70, 103),
- (71, 111),
- (75, 110),
- (78, 107),
- (79, 105),
- (82, 104),
- (86, 696),
- (96, 698),
- (100, 694),
- (109, 693),
- (120, 632),
- (153, 620),
- (165, 632),
- (168, 624),
- (176, 632),
- (0, 409),
- (9, 407),
- (18, 409),
- (37, 528),
- (62, 113),
- (70, 111),
- (77, 110),
- (79, 105),
- (80, 110),
- (81, 109),
- (82, 108),
- (86, 696),
- (96, 698),
- (100, 694),
- (109, 693),
- (120, 632),
- (153, 620),
- (165, 632),
- (168, 624),
- (176, 632),
- (0, 409),
- (9, 407),
- (18, 409),
- (35, 528),
- (62, 113),
- (70, 112),
- (74, 111),
- (78, 110),
- (81, 109),
- (82, 108),
- (86, 699),
- (92, 698),
- (100, 694),
- (111, 693),
- (120, 632),
- (153, 620),
- (165, 632),
- (168, 624),
- (176, 632),
- (0, 409),
- (9, 408),
- (18, 409),
- (34, 528),
- (62, 113),
- (72, 112),
- (74, 111),
- (80, 109),
- (82, 108),
- (86, 699),
- (92, 698),
- (100, 694),
- (111, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (153, 620),
- (165, 632),
- (168, 624),
- (176, 632),
- (0, 409),
- (9, 408),
- (18, 409),
- (34, 528),
- (62, 113),
- (72, 112),
- (75, 111),
- (79, 109),
- (82, 108),
- (86, 699),
- (92, 698),
- (100, 694),
- (113, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (153, 620),
- (165, 632),
- (168, 624),
- (176, 632),
- (0, 409),
- (9, 408),
- (18, 409),
- (34, 528),
- (62, 113),
- (73, 112),
- (75, 111),
- (79, 109),
- (80, 108),
- (84, 699),
- (92, 698),
- (100, 694),
- (113, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (148, 620),
- (157, 626),
- (165, 632),
- (168, 625),
- (175, 632),
- (178, 623),
- (0, 409),
- (9, 408),
- (18, 409),
- (34, 528),
- (62, 113),
- (72, 112),
- (75, 116),
- (78, 109),
- (80, 108),
- (84, 699),
- (92, 698),
- (100, 694),
- (113, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (148, 620),
- (157, 626),
- (165, 632),
- (168, 625),
- (175, 632),
- (178, 623),
- (0, 409),
- (9, 408),
- (18, 409),
- (34, 528),
- (62, 113),
- (70, 112),
- (74, 116),
- (78, 109),
- (79, 108),
- (83, 699),
- (92, 698),
- (104, 694),
- (115, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (148, 620),
- (157, 626),
- (165, 632),
- (168, 625),
- (175, 632),
- (178, 623),
- (0, 409),
- (9, 408),
- (18, 409),
- (35, 528),
- (62, 113),
- (65, 119),
- (68, 113),
- (70, 112),
- (73, 116),
- (77, 115),
- (79, 114),
- (83, 699),
- (92, 698),
- (104, 694),
- (115, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (148, 620),
- (157, 626),
- (165, 169),
- (176, 180),
- (0, 409),
- (9, 408),
- (18, 409),
- (36, 528),
- (62, 113),
- (65, 119),
- (68, 118),
- (70, 112),
- (71, 116),
- (77, 115),
- (78, 114),
- (82, 699),
- (92, 698),
- (104, 694),
- (117, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (148, 620),
- (157, 626),
- (165, 169),
- (176, 180),
- (0, 409),
- (10, 410),
- (18, 409),
- (37, 528),
- (62, 113),
- (64, 119),
- (68, 118),
- (69, 116),
- (76, 115),
- (78, 114),
- (82, 699),
- (92, 698),
- (107, 694),
- (117, 693),
- (120, 632),
- (134, 630),
- (144, 632),
- (148, 620),
- (157, 626),
- (165, 169),
- (176, 180),
- (0, 409),
- (10, 410),
- (18, 409),
- (38, 528),
- (61, 119),
- (68, 118),
- (70, 116),
- (76, 115),
- (77, 114),
- (81, 699),
- (92, 698),
- (107, 694),
- (119, 693),
- (120, 632),
- (133, 631),
- (149, 628),
- (157, 626),
- (165, 169),
- (168, 170),
- (173, 169),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (38, 528),
- (60, 120),
- (68, 118),
- (70, 116),
- (75, 115),
- (77, 114),
- (81, 699),
- (92, 698),
- (107, 694),
- (119, 693),
- (120, 632),
- (133, 631),
- (149, 628),
- (157, 626),
- (165, 169),
- (168, 170),
- (173, 169),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (38, 528),
- (60, 120),
- (69, 118),
- (70, 117),
- (74, 115),
- (76, 114),
- (80, 683),
- (107, 684),
- (120, 632),
- (133, 631),
- (149, 628),
- (157, 627),
- (165, 169),
- (173, 173),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (38, 528),
- (58, 120),
- (68, 118),
- (70, 117),
- (72, 115),
- (75, 114),
- (79, 683),
- (107, 684),
- (120, 632),
- (133, 631),
- (149, 628),
- (157, 627),
- (165, 169),
- (173, 173),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (38, 528),
- (58, 120),
- (69, 118),
- (70, 115),
- (73, 114),
- (77, 683),
- (107, 684),
- (124, 632),
- (133, 631),
- (147, 628),
- (157, 627),
- (166, 174),
- (173, 173),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (39, 528),
- (58, 120),
- (68, 124),
- (69, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (107, 684),
- (124, 632),
- (133, 631),
- (147, 628),
- (156, 627),
- (166, 174),
- (173, 173),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (39, 528),
- (58, 120),
- (59, 126),
- (60, 125),
- (68, 124),
- (69, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (107, 684),
- (124, 632),
- (133, 631),
- (147, 628),
- (156, 627),
- (166, 174),
- (173, 173),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (39, 528),
- (58, 126),
- (62, 125),
- (68, 124),
- (69, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (107, 684),
- (124, 632),
- (133, 631),
- (145, 629),
- (156, 627),
- (166, 174),
- (173, 173),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (40, 528),
- (58, 126),
- (62, 125),
- (67, 124),
- (69, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (107, 684),
- (124, 632),
- (133, 631),
- (145, 629),
- (156, 627),
- (166, 174),
- (173, 173),
- (176, 181),
- (0, 409),
- (10, 410),
- (18, 409),
- (40, 528),
- (56, 126),
- (62, 129),
- (65, 128),
- (67, 124),
- (68, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (102, 685),
- (115, 684),
- (124, 632),
- (133, 631),
- (145, 629),
- (156, 627),
- (166, 174),
- (176, 171),
- (0, 409),
- (10, 410),
- (18, 409),
- (41, 528),
- (55, 126),
- (62, 129),
- (64, 128),
- (67, 127),
- (68, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (102, 685),
- (115, 684),
- (124, 632),
- (133, 631),
- (145, 629),
- (156, 627),
- (166, 174),
- (176, 171),
- (0, 409),
- (10, 410),
- (18, 409),
- (44, 528),
- (54, 126),
- (59, 133),
- (62, 129),
- (67, 127),
- (69, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (102, 685),
- (115, 684),
- (124, 632),
- (133, 631),
- (145, 629),
- (156, 632),
- (170, 175),
- (176, 171),
- (0, 409),
- (9, 410),
- (18, 409),
- (47, 528),
- (54, 126),
- (58, 133),
- (62, 132),
- (63, 129),
- (67, 130),
- (68, 127),
- (69, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (102, 685),
- (115, 684),
- (124, 632),
- (133, 631),
- (145, 629),
- (156, 632),
- (170, 175),
- (176, 171),
- (0, 409),
- (9, 410),
- (18, 409),
- (48, 528),
- (54, 126),
- (58, 133),
- (62, 132),
- (64, 131),
- (66, 130),
- (68, 127),
- (69, 123),
- (70, 122),
- (71, 121),
- (77, 683),
- (102, 685),
- (115, 684),
- (124, 632),
- (170, 175),
- (176, 171),
- (0, 409),
- (8, 410),
- (18, 409),
- (48, 528),
- (55, 126),
- (57, 133),
- (62, 132),
- (65, 131),
- (66, 130),
- (68, 127),
- (69, 123),
- (70, 122),
- (72, 121),
- (77, 683),
- (102, 685),
- (115, 684),
- (124, 632),
- (170, 177),
- (0, 409),
- (8, 410),
- (18, 409),
- (48, 528),
- (56, 133),
- (62, 132),
- (66, 130),
- (67, 138),
- (69, 127),
- (70, 136),
- (71, 135),
- (72, 134),
- (77, 683),
- (102, 685),
- (115, 684),
- (124, 632),
- (170, 177),
- (0, 409),
- (8, 410),
- (18, 409),
- (49, 528),
- (57, 133),
- (62, 132),
- (66, 138),
- (68, 137),
- (69, 127),
- (70, 136),
- (71, 135),
- (72, 134),
- (77, 683),
- (102, 685),
- (115, 684),
- (120, 632),
- (170, 177),
- (177, 178),
- (179, 177),
- (0, 409),
- (9, 410),
- (18, 409),
- (49, 528),
- (56, 142),
- (58, 133),
- (62, 141),
- (66, 138),
- (67, 137),
- (69, 127),
- (71, 135),
- (72, 134),
- (77, 683),
- (100, 686),
- (102, 685),
- (115, 684),
- (120, 632),
- (170, 177),
- (177, 178),
- (179, 177),
- (0, 409),
- (9, 410),
- (18, 409),
- (50, 528),
- (55, 142),
- (58, 133),
- (62, 141),
- (66, 138),
- (67, 137),
- (70, 127),
- (71, 135),
- (72, 134),
- (77, 687),
- (85, 683),
- (97, 686),
- (102, 685),
- (115, 684),
- (119, 632),
- (170, 177),
- (0, 409),
- (10, 410),
- (27, 409),
- (51, 528),
- (53, 142),
- (58, 133),
- (62, 141),
- (65, 140),
- (67, 139),
- (70, 127),
- (71, 135),
- (72, 134),
- (77, 687),
- (85, 683),
- (97, 686),
- (105, 684),
- (119, 632),
- (170, 179),
- (0, 409),
- (10, 410),
- (26, 409),
- (52, 528),
- (53, 142),
- (58, 133),
- (62, 141),
- (65, 140),
- (67, 139),
- (69, 127),
- (71, 135),
- (72, 134),
- (77, 687),
- (85, 683),
- (94, 686),
- (105, 684),
- (118, 632),
- (170, 179),
- (0, 409),
- (6, 411),
- (16, 410),
- (25, 409),
- (53, 142),
- (58, 133),
- (62, 146),
- (63, 141),
- (65, 140),
- (67, 139),
- (69, 127),
- (71, 135),
- (73, 134),
- (77, 687),
- (85, 683),
- (94, 686),
- (105, 684),
- (118, 632),
- (170, 179),
- (0, 409),
- (6, 411),
- (16, 410),
- (23, 409),
- (56, 133),
- (62, 146),
- (65, 140),
- (66, 139),
- (70, 127),
- (71, 136),
- (72, 135),
- (73, 134),
- (80, 683),
- (91, 686),
- (98, 756),
- (105, 684),
- (117, 632),
- (174, 688),
- (0, 409),
- (6, 411),
- (16, 410),
- (22, 409),
- (56, 133),
- (62, 146),
- (68, 139),
- (70, 127),
- (71, 136),
- (72, 135),
- (74, 134),
- (80, 683),
- (91, 686),
- (98, 756),
- (105, 684),
- (117, 632),
- (174, 688),
- (0, 409),
- (6, 411),
- (16, 410),
- (21, 409),
- (56, 133),
- (62, 146),
- (71, 145),
- (72, 136),
- (73, 135),
- (74, 134),
- (80, 683),
- (88, 686),
- (95, 756),
- (100, 692),
- (105, 684),
- (117, 632),
- (174, 688),
- (0, 409),
- (6, 411),
- (16, 410),
- (21, 409),
- (56, 133),
- (62, 146),
- (71, 145),
- (72, 136),
- (73, 135),
- (74, 134),
- (80, 683),
- (88, 686),
- (95, 756),
- (100, 692),
- (105, 684),
- (117, 632),
- (174, 688),
- (0, 409),
- (6, 411),
- (16, 410),
- (21, 409),
- (60, 133),
- (62, 146),
- (71, 145),
- (72, 136),
- (73, 135),
- (74, 134),
- (80, 683),
- (85, 686),
- (92, 756),
- (95, 692),
- (105, 684),
- (117, 632),
- (174, 688),
- (0, 409),
- (6, 411),
- (16, 410),
- (21, 409),
- (62, 146),
- (71, 145),
- (72, 136),
- (73, 135),
- (74, 143),
- (81, 683),
- (85, 686),
- (92, 756),
- (95, 692),
- (105, 684),
- (117, 632),
- (174, 688),
- (0, 409),
- (6, 411),
- (16, 410),
- (21, 409),
- (62, 146),
- (71, 145),
- (72, 144),
- (74, 143),
- (81, 686),
- (89, 756),
- (94, 692),
- (105, 684),
- (117, 632),
- (174, 688),
- (0, 409),
- (6, 411),
- (16, 410),
- (21, 409),
- (63, 146),
- (71, 145),
- (72, 144),
- (75, 143),
- (81, 686),
- (89, 756),
- (94, 692),
- (105, 684),
- (117, 632),
- (170, 689),
- (0, 409),
- (8, 410),
- (22, 409),
- (64, 146),
- (71, 145),
- (72, 144),
- (75, 143),
- (81, 686),
- (86, 692),
- (105, 684),
- (117, 632),
- (170, 689),
- (0, 409),
- (8, 410),
- (22, 409),
- (64, 146),
- (71, 145),
- (72, 144),
- (75, 143),
- (81, 686),
- (86, 692),
- (105, 684),
- (117, 632),
- (170, 689),
- (0, 409),
- (8, 410),
- (22, 409),
- (66, 146),
- (71, 145),
- (72, 144),
- (75, 143),
- (83, 692),
- (105, 684),
- (117, 632),
- (170, 689),
- (0, 409),
- (6, 410),
- (22, 152),
- (24, 409),
- (66, 146),
- (71, 145),
- (72, 144),
- (76, 143),
- (83, 692),
- (105, 684),
- (117, 632),
- (170, 690),
- (0, 409),
- (4, 410),
- (22, 152),
- (26, 409),
- (66, 146),
- (71, 145),
- (72, 144),
- (76, 143),
- (83, 692),
- (105, 684),
- (117, 632),
- (170, 690),
- (0, 409),
- (2, 410),
- (22, 152),
- (28, 409),
- (66, 146),
- (71, 145),
- (73, 144),
- (76, 143),
- (83, 692),
- (105, 684),
- (117, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (10, 156),
- (23, 152),
- (30, 409),
- (55, 148),
- (65, 409),
- (67, 146),
- (71, 145),
- (73, 144),
- (76, 143),
- (83, 692),
- (105, 684),
- (117, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (10, 156),
- (24, 152),
- (32, 409),
- (55, 148),
- (65, 409),
- (67, 146),
- (71, 145),
- (73, 144),
- (76, 143),
- (83, 692),
- (105, 684),
- (125, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (10, 156),
- (26, 152),
- (34, 409),
- (55, 148),
- (65, 409),
- (68, 146),
- (71, 145),
- (72, 144),
- (76, 143),
- (83, 692),
- (105, 684),
- (125, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (10, 156),
- (28, 152),
- (31, 151),
- (40, 409),
- (55, 148),
- (65, 147),
- (71, 144),
- (76, 143),
- (83, 692),
- (105, 684),
- (127, 691),
- (147, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (10, 156),
- (29, 152),
- (31, 151),
- (40, 409),
- (55, 148),
- (65, 147),
- (71, 144),
- (75, 143),
- (83, 692),
- (105, 684),
- (127, 691),
- (147, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (9, 156),
- (22, 153),
- (31, 151),
- (40, 409),
- (55, 148),
- (65, 147),
- (71, 144),
- (74, 143),
- (83, 692),
- (105, 684),
- (127, 691),
- (154, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (8, 156),
- (22, 153),
- (31, 151),
- (40, 150),
- (59, 149),
- (65, 147),
- (72, 143),
- (83, 692),
- (113, 684),
- (127, 691),
- (154, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (7, 732),
- (18, 156),
- (22, 153),
- (31, 151),
- (40, 150),
- (59, 149),
- (73, 692),
- (113, 684),
- (127, 691),
- (157, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (7, 732),
- (18, 156),
- (22, 153),
- (31, 150),
- (59, 149),
- (73, 692),
- (117, 684),
- (127, 691),
- (157, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (7, 732),
- (22, 153),
- (31, 150),
- (59, 149),
- (73, 692),
- (117, 684),
- (127, 691),
- (160, 632),
- (170, 690),
- (0, 412),
- (2, 410),
- (7, 732),
- (22, 153),
- (31, 150),
- (59, 149),
- (73, 692),
- (117, 684),
- (127, 691),
- (160, 632),
- (170, 690),
- (0, 414),
- (5, 156),
- (15, 732),
- (22, 153),
- (31, 150),
- (52, 154),
- (59, 149),
- (73, 692),
- (148, 691),
- (170, 690),
- (0, 414),
- (5, 156),
- (15, 732),
- (22, 153),
- (31, 150),
- (52, 154),
- (62, 149),
- (73, 692),
- (148, 691),
- (0, 414),
- (5, 156),
- (56, 154),
- (65, 692),
- (149, 691),
- (0, 414),
- (5, 156),
- (54, 155),
- (60, 154),
- (65, 692),
- (149, 691),
- (0, 414),
- (5, 156),
- (56, 155),
- (65, 692),
- (150, 691),
- (0, 414),
- (5, 156),
- (59, 155),
- (67, 692),
- (159, 691),
- (0, 414),
- (5, 156),
- (59, 155),
- (70, 692),
- (159, 691),
- (0, 414),
- (5, 156),
- (60, 155),
- (70, 692),
- (162, 691),
- (0, 414),
- (5, 156),
- (60, 155),
- (76, 692),
- (162, 691),
- (0, 729),
- (11, 156),
- (60, 155),
- (76, 692),
- (162, 691),
- (0, 729),
- (11, 156),
- (60, 155),
- (76, 692),
- (141, 728),
- (0, 729),
- (14, 157),
- (60, 155),
- (76, 692),
- (141, 728),
- (0, 729),
- (19, 157),
- (60, 155),
- (76, 692),
- (95, 729),
- (103, 692),
- (141, 728),
- (0, 729),
- (21, 157),
- (60, 155),
- (71, 729),
- (103, 692),
- (120, 729),
- (129, 692),
- (141, 728),
- (0, 729),
- (24, 157),
- (60, 155),
- (66, 729),
- (141, 728),
- (0, 729),
- (27, 157),
- (56, 729),
- (147, 728),
- (0, 729),
- (32, 157),
- (50, 729),
- (152, 728),
- (0, 729),
- (162, 728),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
- (0, 729),
-];
\ No newline at end of file
@@ -10,6 +10,13 @@
 //! assert_eq!(name, "SOUTH ISLAND, NEW ZEALAND");
 //! ```
 //!
+//! # Revisions
+//!
+//! Only the tables of the 1995 revision (757 geographic regions, Young et al.,
+//! 1996) are included, see [`Regionalization::fe1995`].  The earlier 729
+//! region scheme (Flinn, Engdahl and Hill, 1974) is not supported: its
+//! quadrant index, section and names files are not part of `data/`.
+//!
 //! # References
 //!
 //!    - [Young, J.B., Presgrave, B.W., Aichele, H., Wiens, D.A. and Flinn, E.A., 1996, The Flinn-Engdahl Regionalisation Scheme: the 1995 revision, Physics of the Earth and Planetary Interiors, v. 96, p. 223-297.](https://www.sciencedirect.com/science/article/pii/003192019603141X)
@@ -22,43 +29,162 @@
 //! [https://earthquake.usgs.gov/learn/topics/flinn_engdahl.php](https://earthquake.usgs.gov/learn/topics/flinn_engdahl.php)
 //!
 //! [ftp://hazards.cr.usgs.gov/feregion/fe_1995/](ftp://hazards.cr.usgs.gov/feregion/fe_1995/)
-//! 
+//!
+//! # Features
+//!
+//!    - `std` (default) - Everything beyond the basic lookups: cells, polygons,
+//!      search, distances, batch lookups over slices and the table file readers.
+//!      Without it the crate is `no_std` and needs neither an allocator nor a
+//!      floating point math library; [`region`], [`region_number`],
+//!      [`seismic_region`], [`seismic_region_number`], [`geographic_region`],
+//!      [`Lookup`] and [`region_numbers_iter`] remain available.
+//!    - `dense-grid` (default) - Precomputed 1 x 1 degree table (131 KB) for
+//!      single index lookups; without it lookups search the compact latitude
+//!      tiers (14 KB).  The tables only stay within 14 KB with
+//!      `default-features = false`, as this feature is on by default
+//!    - `parallel` - Split large batch lookups across threads, implies `std`
+//!
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 /// Errors for Flinn_Engdahl
 #[derive(Debug,Copy,Clone,PartialEq)]
 pub enum RegionError {
-    /// Longitude is out of allowable range
+    /// Longitude is out of the range accepted by the [`LongitudePolicy`] in use
     BadLongitude,
     /// Latitude is out of allowable range
     BadLatitude,
+    /// Region number is out of allowable range
+    BadRegionNumber,
+    /// Region name is not a known region name
+    UnknownRegionName,
+    /// Latitude is NaN or infinite
+    NonFiniteLatitude,
+    /// Longitude is NaN or infinite
+    NonFiniteLongitude,
+    /// Tolerance or uncertainty is negative or not finite
+    BadTolerance,
 }
 
+impl core::fmt::Display for RegionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            RegionError::BadLongitude => "longitude out of range",
+            RegionError::BadLatitude => "latitude out of range [-90, 90]",
+            RegionError::BadRegionNumber => "region number out of range",
+            RegionError::UnknownRegionName => "unknown region name",
+            RegionError::NonFiniteLatitude => "latitude is not finite",
+            RegionError::NonFiniteLongitude => "longitude is not finite",
+            RegionError::BadTolerance => "tolerance or uncertainty is negative or not finite",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegionError {}
+
+mod geographic_region;
+pub use geographic_region::{GeographicRegion, geographic_region};
+mod lookup;
+pub use lookup::{Antimeridian, Backend, Lookup, LongitudePolicy};
+mod tiers;
+#[cfg(feature = "dense-grid")]
+mod dense_grid;
+mod batch;
+pub use batch::{RegionNumbers, region_numbers_iter};
+#[cfg(feature = "std")]
+pub use batch::region_numbers;
+
+#[cfg(feature = "std")]
+mod crosswalk;
+#[cfg(feature = "std")]
+pub use crosswalk::{Overlap, crosswalk};
+#[cfg(feature = "std")]
+mod cells;
+#[cfg(feature = "std")]
+pub use cells::{Cell, cells};
+#[cfg(feature = "std")]
+mod polygon;
+#[cfg(feature = "std")]
+pub use polygon::{Polygon, polygons};
+#[cfg(feature = "std")]
+mod geojson;
+#[cfg(feature = "std")]
+pub use geojson::{region_geojson, world_geojson};
+#[cfg(feature = "std")]
+mod adjacency;
+#[cfg(feature = "std")]
+pub use adjacency::{Border, Neighbor, borders, neighbors};
+#[cfg(feature = "std")]
+mod search;
+#[cfg(feature = "std")]
+pub use search::{SearchMatch, search_regions};
+#[cfg(feature = "std")]
+mod name_style;
+#[cfg(feature = "std")]
+pub use name_style::{NameStyle, region_with_style};
+#[cfg(feature = "std")]
+mod geometry;
+#[cfg(feature = "std")]
+mod candidates;
+#[cfg(feature = "std")]
+pub use candidates::{Candidates, Tolerance, region_candidates};
+#[cfg(feature = "std")]
+mod probability;
+#[cfg(feature = "std")]
+pub use probability::{RegionProbability, Uncertainty, region_probabilities};
+#[cfg(feature = "std")]
+mod boundary;
+#[cfg(feature = "std")]
+pub use boundary::{BoundaryDistance, distance_to_boundary};
+#[cfg(feature = "std")]
+mod data;
+#[cfg(feature = "std")]
+pub use data::DataError;
+mod regionalization;
+pub use regionalization::Regionalization;
+#[cfg(feature = "std")]
+pub use regionalization::LoadError;
+#[cfg(feature = "std")]
+mod csv;
+#[cfg(feature = "std")]
+pub use csv::{CSV_COLUMNS, Column, CsvAnnotator, CsvError, CsvReport, RowError, RowProblem};
+#[cfg(feature = "std")]
+mod quakeml;
+#[cfg(feature = "std")]
+pub use quakeml::{EventError, EventProblem, FLINN_ENGDAHL_TYPE, QuakeMlAnnotator, QuakeMlError, QuakeMlMode, QuakeMlReport};
+#[cfg(feature = "std")]
+mod sac;
+#[cfg(feature = "std")]
+pub use sac::{ByteOrder, IevregCheck, SAC_UNDEFINED, SacError, SacHeader, sac_check_ievreg, sac_fill_ievreg};
+#[cfg(feature = "std")]
+mod css;
+#[cfg(feature = "std")]
+pub use css::{NULL_REGION, ORIGIN_RECORD_LEN, OriginAnnotator, OriginError, OriginProblem, OriginRecord, OriginReport};
+
 /// Convert lat,lon position in region number
 ///
 /// # Arguments
 ///  - lat - Latitude [-90, 90]
-///  - lon - Longitude [-360, 360]
+///  - lon - Longitude, range depends on `policy`
+///  - policy - Handling of longitudes outside of [-180, 180]
+///  - antimeridian - Side of the antimeridian for longitudes of +/-180
 ///  - quadid - Quadrant Index ([274, 183, 92, 1])
-///  - llindx - (Tier onset index, length of segments in tier)
-///  - lattiers - (Longitude (truncated), Region Number)
+///  - tiers - Latitude tiers: segment onsets, longitudes and region numbers
 ///
 fn namnum(lat: f64, lon: f64,
+          policy: LongitudePolicy,
+          antimeridian: Antimeridian,
           quadid: &[usize],
-          llindx: &[(usize,usize)],
-          lattiers: &[(usize,usize)]) -> Result<usize,RegionError> {
+          tiers: &tiers::Tiers<'_>) -> Result<usize,RegionError> {
+    if !lat.is_finite() {
+        return Err(RegionError::NonFiniteLatitude);
+    }
     if lat.abs() > 90.0 {
         return Err(RegionError::BadLatitude);
     }
-    if lon.abs() > 360.0 {
-        return Err(RegionError::BadLongitude);
-    }
-
-    let mut lon = lon;
-    if lon < -180.0 {
-        lon = lon + 360.0;
-    } else if lon > 180.0 {
-        lon = lon - 360.0;
-    }
+    let lon = crate::lookup::normalize_longitude(lon, policy, antimeridian)?;
 
     // Find the Hemisphere of the input position
     let quadon = match (lat >= 0.0, lon >= 0.0) {
@@ -69,24 +195,13 @@ fn namnum(lat: f64, lon: f64,
     };
 
     // Truncate the latitude and longitude
-    let lt = lat.abs().trunc() as usize;
-    let ln = lon.abs().trunc() as usize;
+    let lt = lat.abs() as usize;
+    let ln = lon.abs() as usize;
 
     // Get first index of latitudes in the correct quadrant
     let recnbr = quadon + lt - 1;
-    // tieron - Tier Onset
-    // nbrbdy - Number of segments in tier
-    let (tieron, nbrbdy) = llindx[ recnbr ];
-    // Search through the seguments for longitude
-    let mut pair = lattiers[tieron-1];
-    for i in tieron - 1 .. tieron - 1 + nbrbdy {
-        pair = lattiers[i];
-        if lattiers[i].0 > ln {
-            pair = lattiers[i-1];
-            break;
-        }
-    }
-    Ok(pair.1)
+    // Search through the segments of the tier for longitude
+    Ok(tiers.lookup(recnbr, ln))
 }
 
 /// Get the Flinn_Engdahl region name from a location at (`lat`,`lon`)
@@ -101,15 +216,15 @@ fn namnum(lat: f64, lon: f64,
 ///   - lat - Latitude  [-90, 90]
 ///   - lon - Longitude [-360, 360]
 ///
-///   Note: Values returned at longitude of -180 and 180 are different
+///   Note: Values returned at longitude of -180 and 180 are different,
+///   see [`Lookup`] and [`Antimeridian`] to choose a side
 ///
 /// # Returns
 ///   - Flinn_Engdahl Region Name
 ///
 ///
 pub fn region(lat: f64, lon: f64) -> Result<&'static str, RegionError> {
-    let n = crate::namnum(lat, lon, &crate::quadids(), &LLINDX, &LAT_TIERS)?;
-    Ok(NAMES[n-1])
+    Lookup::new().region(lat, lon)
 }
 /// Get the Flinn_Engdahl region numner from a location at (`lat`,`lon`)
 ///
@@ -128,128 +243,94 @@ pub fn region(lat: f64, lon: f64) -> Result<&'static str, RegionError> {
 ///
 ///
 pub fn region_number(lat: f64, lon: f64) -> Result<usize, RegionError> {
-    crate::namnum(lat, lon, &crate::quadids(), &LLINDX, &LAT_TIERS)
+    Lookup::new().region_number(lat, lon)
 }
 
-
-const fn quadids() -> [usize; 4] {
-    [274, 183, 92, 1]
-}
-
-/// Read in data from quadsidx.asc
+/// Get the Flinn_Engdahl seismic region name from a location at (`lat`,`lon`)
 ///
-/// Output is the "tier" and number of segments
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let region = fe::seismic_region(37.871593, -122.272743).unwrap();
+///  assert_eq!(region, "CALIFORNIA-NEVADA REGION");
+/// ```
 ///
-/// The tier represent the offset into the latitude tiers (lattiers) array
-/// the number of segmens is the number of segments in that latitude tier
+/// # Arguments
+///   - lat - Latitude  [-90, 90]
+///   - lon - Longitude [-360, 360]
 ///
-/// quadsidx.asc include the number of segments in each tier, organized by quadrant.
-/// For the 1995 version, there should be 364 total tiers, 91 per quadrant
-/// Each tier represents a latitude, starting at 0 and working towards the pole by 1 degree
-/// [0..90] inclusive range
+/// # Returns
+///   - Flinn_Engdahl Seismic Region Name
 ///
-fn llindx() -> Vec<(usize,usize)> {
-    let base = std::path::Path::new("data");
-    let data = std::fs::read_to_string(base.join("quadsidx.asc")).unwrap();
-    let mut carry = 1;
-    let mut out = vec![];
-    for item in data.split_whitespace() {
-        let v = item.parse().unwrap();
-        out.push( (carry, v) );
-        carry += v;
-    }
-    out
+pub fn seismic_region(lat: f64, lon: f64) -> Result<&'static str, RegionError> {
+    Lookup::new().seismic_region(lat, lon)
 }
 
-fn lat_lon_index_write<P: AsRef<std::path::Path>>(llindx: &[(usize,usize)], file: P) {
-    let mut out = String::new();
-    let n = llindx.len();
-    out += &format!("const LLINDX: [(usize,usize); {}] = [\n", n);
-    for (a,b) in llindx {
-        out += &format!(" ({}, {}),\n", a,b);
-    }
-    out += &format!("];\n");
-    std::fs::write(file, out).unwrap();
-}
-
-/// Read in data from the quadrant section as Latitude Tiers
+/// Get the Flinn_Engdahl seismic region number from a location at (`lat`,`lon`)
 ///
-/// Data within the file is organized as (longitude, region number) pairs
-/// Within each Tier, the longitude is always increasing
+/// Seismic regions [1, 50] are groupings of the geographic regions
 ///
-fn latitude_tiers_read() -> Vec<(usize,usize)> {
-    let base = std::path::Path::new("data");
-    let sects = ["nesect.asc", "nwsect.asc", "sesect.asc", "swsect.asc" ];
-    let mut out = vec![];
-    for sect in sects.iter() {
-        let file = base.join(sect);
-        let data = std::fs::read_to_string(file).unwrap();
-        let mut items = data.split_whitespace();
-        while let (Some(a),Some(b)) = (items.next(), items.next()) {
-            let a = a.parse().unwrap();
-            let b = b.parse().unwrap();
-            out.push( (a,b) )
-        }
-    }
-    out
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let region = fe::seismic_region_number(-42.448299, 171.214005).unwrap();
+///  assert_eq!(region, 11);
+/// ```
+///
+/// # Arguments
+///   - lat - Latitude  [-90, 90]
+///   - lon - Longitude [-360, 360]
+///
+/// # Returns
+///   - Flinn_Engdahl Seismic Region Number
+///
+pub fn seismic_region_number(lat: f64, lon: f64) -> Result<usize, RegionError> {
+    Lookup::new().seismic_region_number(lat, lon)
 }
 
-fn latitude_tiers_write<P: AsRef<std::path::Path>>(lattiers: &[(usize,usize)], file: P) {
-    let mut out = String::new();
-    let n = lattiers.len();
-    out += &format!("const LAT_TIERS: [(usize,usize); {}] = [\n", n);
-    for (a,b) in lattiers {
-        out += &format!( " ({}, {}),\n", a,b);
-    }
-    out += &format!("];");
-    std::fs::write(file, out).unwrap();
+
+const fn quadids() -> [usize; 4] {
+    [274, 183, 92, 1]
 }
 
-include!("names.rs");
-include!("latitude_tiers.rs");
-include!("lat_lon_index.rs");
+// Tables generated by build.rs from data/*.asc
+include!(concat!(env!("OUT_DIR"), "/names.rs"));
+include!(concat!(env!("OUT_DIR"), "/seismic_names.rs"));
+include!(concat!(env!("OUT_DIR"), "/seismic_regions.rs"));
+#[cfg(feature = "std")]
+include!(concat!(env!("OUT_DIR"), "/names_expanded.rs"));
 
-fn names_read() -> Vec<String> {
-    let base = std::path::Path::new("data");
-    let data = std::fs::read_to_string(base.join("names.asc")).unwrap();
-    data.lines().map(|x| x.to_string()).collect()
-}
 
-fn names_write<P: AsRef<std::path::Path>>(names: &[String], file: P) {
-    let mut out = String::new();
-    let n = names.len();
-    out += &format!("const NAMES: [&str; {}] = [\n", n);
-    for n in names {
-        out += &format!(" \"{}\",\n", n);
-    }
-    out += &format!("];");
-    std::fs::write(file, out).unwrap();
+/// Read and parse a data file, as the build script does
+#[cfg(all(test, feature = "std"))]
+fn read_data<T>(file: &str, parse: impl Fn(&str, &str) -> Result<T, data::DataError>) -> T {
+    let text = std::fs::read_to_string(std::path::Path::new("data").join(file)).unwrap();
+    parse(file, &text).unwrap()
 }
-
-#[allow(dead_code)]
-fn reformat_names<P: AsRef<std::path::Path>>(file: P) {
-    names_write( &names_read(), file );
+#[cfg(all(test, feature = "std"))]
+fn llindx() -> Vec<(usize,usize)> {
+    read_data("quadsidx.asc", data::parse_quadsidx)
 }
-#[allow(dead_code)]
-fn reformat_latitude_tiers<P: AsRef<std::path::Path>>(file: P) {
-    latitude_tiers_write( &latitude_tiers_read(), file );
+#[cfg(all(test, feature = "std"))]
+fn latitude_tiers_read() -> Vec<(usize,usize)> {
+    let sects : Vec<_> = data::SECTS.iter().map(|f| (*f, read_data(f, data::parse_sect))).collect();
+    data::latitude_tiers(&llindx(), &sects, NAMES.len()).unwrap()
 }
-#[allow(dead_code)]
-fn reformat_lat_lon_index<P: AsRef<std::path::Path>>(file: P) {
-    lat_lon_index_write( &llindx(), file );
+#[cfg(all(test, feature = "std"))]
+fn names_read() -> Vec<String> {
+    read_data("names.asc", data::parse_names)
 }
 
-
 #[cfg(test)]
 mod tests {
     #[test]
+    #[cfg(feature = "std")]
     fn check_simple() {
         let names = crate::names_read();
         assert_eq!(names.len(), 757);
         let n = crate::namnum(-90.0, -90.0,
+                              crate::LongitudePolicy::Reject,
+                              crate::Antimeridian::AsGiven,
                               &crate::quadids(),
-                              &crate::LLINDX,
-                              &crate::LAT_TIERS).unwrap();
+                              &crate::tiers::TIERS_1995).unwrap();
         assert_eq!(names[n-1], "ANTARCTICA");
         assert_eq!(crate::NAMES[n-1], "ANTARCTICA");
         assert_eq!(n, 729);
@@ -277,30 +358,63 @@ mod tests {
 
     #[test]
     fn check_llindx() {
-        //let index = &crate::llindx();
-        let index = &crate::LLINDX;
-        assert_eq!(index.len(), 364);
-        assert_eq!(index[0], (1,24));
+        let tiers = &crate::tiers::TIERS_1995;
+        assert_eq!(tiers.onsets.len(), 365);
+        assert_eq!(tiers.segments(0), (0,24));
     }
     #[test]
     fn check_lat_tiers() {
-        //let index = &crate::latitude_tiers_read();
-        let index = &crate::LAT_TIERS;
-        assert_eq!(index.len(), 5958);
-        assert_eq!(index[0], (0,561));
+        let tiers = &crate::tiers::TIERS_1995;
+        assert_eq!(tiers.lons.len(), 5958);
+        assert_eq!((tiers.lon(0), tiers.region(0)), (0,561));
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn check_names_expanded() {
+        let names = crate::read_data("names_expanded.asc", crate::data::parse_names);
+        assert_eq!(names.len(), 757);
+        assert_eq!(names, crate::NAMES_EXPANDED);
+        for (short, long) in crate::NAMES.iter().zip(crate::NAMES_EXPANDED.iter()) {
+            assert!(!long.contains('.') || long.contains("St."), "{}", long);
+            assert!(long.len() >= short.len() - 2, "{} {}", short, long);
+        }
     }
     #[test]
-    fn reformat_files() {
-        // crate::reformat_names("src/names.rs");
-        // crate::reformat_latitude_tiers("src/latitude_tiers.rs");
-        // crate::reformat_lat_lon_index("src/lat_lon_index.rs");
+    #[cfg(feature = "std")]
+    fn check_seismic_tables() {
+        let names = crate::read_data("seisnames.asc", crate::data::parse_names);
+        assert_eq!(names.len(), 50);
+        assert_eq!(names, crate::SEISMIC_NAMES);
+        let seis = crate::read_data("seisreg.asc", |f, d| crate::data::parse_seismic_regions(f, d, 757, 50));
+        assert_eq!(seis.len(), 757);
+        assert_eq!(seis, crate::SEISMIC_REGIONS);
+        assert_eq!(crate::SEISMIC_REGIONS.len(), crate::NAMES.len());
+        for s in crate::SEISMIC_REGIONS.iter() {
+            assert!((1..=50).contains(s));
+        }
+        // Every seismic region contains at least one geographic region
+        for s in 1..=50 {
+            assert!(crate::SEISMIC_REGIONS.contains(&s), "{}", s);
+        }
+    }
+    #[test]
+    fn check_seismic_region() {
+        let (lat, lon) = (-42.448299, 171.214005);
+        assert_eq!(crate::seismic_region_number(lat, lon), Ok(11));
+        assert_eq!(crate::seismic_region(lat, lon), Ok("NEW ZEALAND REGION"));
+        assert_eq!(crate::seismic_region_number(-90., 0.), Ok(50));
+        assert_eq!(crate::seismic_region(-90., 0.), Ok("ANTARCTICA"));
+        assert_eq!(crate::seismic_region(61.0, -150.0), Ok("ALASKA-ALEUTIAN ARC"));
+        assert_eq!(crate::seismic_region(36.5, 70.5), Ok("HINDU KUSH AND PAMIR"));
+        assert_eq!(crate::seismic_region_number(0., 0.), Ok(37));
+        assert_eq!(crate::seismic_region_number(91., 0.), Err(crate::RegionError::BadLatitude));
     }
     #[test]
     fn full_comparison() {
 
         let data = std::fs::read_to_string("data/fe-short.txt").unwrap();
         for line in data.lines() {
-            if line.trim().len() == 0 {
+            if line.trim().is_empty() {
                 continue;
             }
             let mut items = line.split_whitespace();
@@ -323,6 +437,35 @@ mod tests {
         assert_eq!(crate::region_number(-90., 0.), Ok(729));
     }
     #[test]
+    fn non_finite_test() {
+        use crate::RegionError::*;
+        let nan = f64::NAN;
+        let inf = f64::INFINITY;
+        assert_eq!(crate::region_number(nan, 0.), Err(NonFiniteLatitude));
+        assert_eq!(crate::region_number(0., nan), Err(NonFiniteLongitude));
+        assert_eq!(crate::region_number(inf, 0.), Err(NonFiniteLatitude));
+        assert_eq!(crate::region_number(-inf, 0.), Err(NonFiniteLatitude));
+        assert_eq!(crate::region_number(0., inf), Err(NonFiniteLongitude));
+        assert_eq!(crate::region_number(nan, nan), Err(NonFiniteLatitude));
+        assert_eq!(crate::region(nan, 0.), Err(NonFiniteLatitude));
+        assert_eq!(crate::seismic_region_number(0., -inf), Err(NonFiniteLongitude));
+        assert_eq!(crate::seismic_region(nan, 0.), Err(NonFiniteLatitude));
+        assert_eq!(crate::geographic_region(0., nan), Err(NonFiniteLongitude));
+        #[cfg(feature = "std")]
+        assert!(crate::region_with_style(nan, 0., crate::NameStyle::Expanded).is_err());
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn error_trait() {
+        fn lookup(lat: f64, lon: f64) -> Result<usize, Box<dyn std::error::Error>> {
+            Ok(crate::region_number(lat, lon)?)
+        }
+        assert_eq!(lookup(0., 0.).unwrap(), 561);
+        let err = lookup(f64::NAN, 0.).unwrap_err();
+        assert_eq!(err.to_string(), "latitude is not finite");
+        assert_eq!(crate::RegionError::BadLongitude.to_string(), "longitude out of range");
+    }
+    #[test]
     fn lon_test() {
         assert_eq!(crate::region_number(0., 361.), Err(crate::RegionError::BadLongitude));
         assert_eq!(crate::region_number(0., -361.), Err(crate::RegionError::BadLongitude));
@@ -0,0 +1,263 @@
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+use crate::{GeographicRegion, RegionError, Regionalization};
+#[cfg(feature = "std")]
+use crate::NameStyle;
+
+/// Handling of longitudes outside of [-180, 180]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Default)]
+pub enum LongitudePolicy {
+    /// Accept [-360, 360], shifting values beyond +/-180 by 360, reject anything else
+    #[default]
+    Reject,
+    /// Accept any finite value, wrapping it into [-180, 180)
+    ///
+    /// Values already within [-180, 180] are left as is
+    Wrap,
+    /// Accept any finite value, clamping it to [-180, 180]
+    Clamp,
+}
+
+/// Side of the antimeridian a longitude of exactly +/-180 belongs to
+///
+/// The tables carry separate eastern and western values at 180, so
+/// without a choice 180 and -180 can fall in different regions.
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Default)]
+pub enum Antimeridian {
+    /// 180 is looked up in the eastern hemisphere, -180 in the western
+    #[default]
+    AsGiven,
+    /// Both +/-180 are looked up in the eastern hemisphere
+    East,
+    /// Both +/-180 are looked up in the western hemisphere
+    West,
+}
+
+/// Table used to look up region numbers
+///
+/// Both give identical results; the tier scan is the original algorithm
+/// on the compact tables and the default without the `dense-grid` feature.
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Default)]
+#[non_exhaustive]
+pub enum Backend {
+    /// Precomputed 1 x 1 degree table, a single index per lookup
+    #[cfg(feature = "dense-grid")]
+    #[default]
+    DenseGrid,
+    /// Search of the segments within each latitude tier
+    #[cfg_attr(not(feature = "dense-grid"), default)]
+    TierScan,
+}
+
+/// Bring a longitude into [-180, 180] following `policy` and `antimeridian`
+pub(crate) fn normalize_longitude(lon: f64, policy: LongitudePolicy, antimeridian: Antimeridian)
+                                  -> Result<f64, RegionError> {
+    if !lon.is_finite() {
+        return Err(RegionError::NonFiniteLongitude);
+    }
+    let mut lon = match policy {
+        LongitudePolicy::Reject => {
+            if lon.abs() > 360.0 {
+                return Err(RegionError::BadLongitude);
+            }
+            if lon < -180.0 {
+                lon + 360.0
+            } else if lon > 180.0 {
+                lon - 360.0
+            } else {
+                lon
+            }
+        },
+        LongitudePolicy::Wrap => {
+            if lon.abs() <= 180.0 {
+                lon
+            } else {
+                // Euclidean remainder without the float math library
+                let r = (lon + 180.0) % 360.0;
+                if r < 0.0 { r + 180.0 } else { r - 180.0 }
+            }
+        },
+        LongitudePolicy::Clamp => lon.clamp(-180.0, 180.0),
+    };
+    if lon.abs() == 180.0 {
+        lon = match antimeridian {
+            Antimeridian::AsGiven => lon,
+            Antimeridian::East => 180.0,
+            Antimeridian::West => -180.0,
+        };
+    }
+    Ok(lon)
+}
+
+/// Lookup configuration: longitude policy, antimeridian convention and backend
+///
+/// The free functions ([`region`](crate::region), [`region_number`](crate::region_number), ...)
+/// use `Lookup::new()`, i.e. the 1995 revision rejecting longitudes beyond
+/// +/-360 with +/-180 taken as given, looked up in the dense grid.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  use fe::{Antimeridian, Lookup, LongitudePolicy};
+///  let fe = Lookup::new().longitude(LongitudePolicy::Wrap);
+///  assert_eq!(fe.region_number(0.0, 540.0), fe.region_number(0.0, -180.0));
+///  assert!(fe::region_number(0.0, 540.0).is_err());
+///
+///  let fe = Lookup::new().antimeridian(Antimeridian::West);
+///  assert_eq!(fe.region_number(0.0, 180.0), fe.region_number(0.0, -180.0));
+/// ```
+///
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Default)]
+pub struct Lookup {
+    longitude: LongitudePolicy,
+    antimeridian: Antimeridian,
+    backend: Backend,
+}
+
+impl Lookup {
+    /// Default lookup configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the handling of longitudes outside of [-180, 180]
+    pub fn longitude(mut self, policy: LongitudePolicy) -> Self {
+        self.longitude = policy;
+        self
+    }
+    /// Set the side of the antimeridian for longitudes of +/-180
+    pub fn antimeridian(mut self, side: Antimeridian) -> Self {
+        self.antimeridian = side;
+        self
+    }
+    /// Set the table used for lookups
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+    /// Check and normalize a location, returning (lat, lon) with lon in [-180, 180]
+    pub fn normalize(&self, lat: f64, lon: f64) -> Result<(f64, f64), RegionError> {
+        if !lat.is_finite() {
+            return Err(RegionError::NonFiniteLatitude);
+        }
+        if lat.abs() > 90.0 {
+            return Err(RegionError::BadLatitude);
+        }
+        let lon = normalize_longitude(lon, self.longitude, self.antimeridian)?;
+        Ok((lat, lon))
+    }
+    /// Get the region number from a location at (`lat`,`lon`)
+    pub fn region_number(&self, lat: f64, lon: f64) -> Result<usize, RegionError> {
+        self.region_number_in(Regionalization::fe1995(), lat, lon)
+    }
+    /// Get the region number from a location at (`lat`,`lon`) in the regionalization `fe`
+    ///
+    /// Tables without a dense grid, such as those loaded at run time,
+    /// are always looked up with the tier scan.
+    pub fn region_number_in(&self, fe: &Regionalization, lat: f64, lon: f64) -> Result<usize, RegionError> {
+        #[cfg(feature = "dense-grid")]
+        {
+            if let (Backend::DenseGrid, Some(grid)) = (self.backend, fe.grid()) {
+                let (lat, lon) = self.normalize(lat, lon)?;
+                return Ok(grid[crate::dense_grid::grid_index(lat, lon, &crate::quadids())] as usize);
+            }
+        }
+        crate::namnum(lat, lon, self.longitude, self.antimeridian, &crate::quadids(), &fe.tiers())
+    }
+    /// Dense grid and quadrant index when using the dense grid backend
+    #[cfg(all(feature = "std", feature = "dense-grid"))]
+    pub(crate) fn grid(&self) -> Option<(&'static [u16], [usize; 4])> {
+        match self.backend {
+            Backend::DenseGrid => Regionalization::fe1995().grid().map(|g| (g, crate::quadids())),
+            Backend::TierScan => None,
+        }
+    }
+    /// Get the region name from a location at (`lat`,`lon`)
+    pub fn region(&self, lat: f64, lon: f64) -> Result<&'static str, RegionError> {
+        self.region_in(Regionalization::fe1995(), lat, lon)
+    }
+    /// Get the region name from a location at (`lat`,`lon`) in the regionalization `fe`
+    pub fn region_in<'a>(&self, fe: &'a Regionalization, lat: f64, lon: f64) -> Result<&'a str, RegionError> {
+        let n = self.region_number_in(fe, lat, lon)?;
+        Ok(fe.name(n).unwrap())
+    }
+    /// Get the region name in `style` from a location at (`lat`,`lon`)
+    #[cfg(feature = "std")]
+    pub fn region_with_style(&self, lat: f64, lon: f64, style: NameStyle)
+                             -> Result<Cow<'static, str>, RegionError> {
+        let n = self.region_number(lat, lon)?;
+        Ok(style.name(n).unwrap())
+    }
+    /// Get the geographic region from a location at (`lat`,`lon`)
+    pub fn geographic_region(&self, lat: f64, lon: f64) -> Result<GeographicRegion, RegionError> {
+        let n = self.region_number(lat, lon)?;
+        GeographicRegion::try_from(n)
+    }
+    /// Get the seismic region number from a location at (`lat`,`lon`)
+    pub fn seismic_region_number(&self, lat: f64, lon: f64) -> Result<usize, RegionError> {
+        let n = self.region_number(lat, lon)?;
+        Ok(crate::SEISMIC_REGIONS[n-1])
+    }
+    /// Get the seismic region name from a location at (`lat`,`lon`)
+    pub fn seismic_region(&self, lat: f64, lon: f64) -> Result<&'static str, RegionError> {
+        let n = self.seismic_region_number(lat, lon)?;
+        Ok(crate::SEISMIC_NAMES[n-1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RegionError::*;
+
+    #[test]
+    fn reject() {
+        let fe = Lookup::new();
+        assert_eq!(fe.region_number(0., 361.), Err(BadLongitude));
+        assert_eq!(fe.region_number(0., 360.), Ok(561));
+        assert_ne!(fe.region_number(0., 180.), fe.region_number(0., -180.));
+    }
+    #[test]
+    fn wrap() {
+        let fe = Lookup::new().longitude(LongitudePolicy::Wrap);
+        assert_eq!(fe.normalize(0., 540.), Ok((0., -180.)));
+        assert_eq!(fe.normalize(0., 720.5), Ok((0., 0.5)));
+        assert_eq!(fe.normalize(0., -1000.), Ok((0., 80.)));
+        assert_eq!(fe.normalize(0., 180.), Ok((0., 180.)));
+        assert_eq!(fe.normalize(91., 0.), Err(BadLatitude));
+        assert_eq!(fe.region_number(0., 370.5), crate::region_number(0., 10.5));
+        assert_eq!(fe.region_number(0., -1000.), crate::region_number(0., 80.));
+        assert_eq!(fe.region_number(0., f64::NAN), Err(NonFiniteLongitude));
+    }
+    #[test]
+    fn clamp() {
+        let fe = Lookup::new().longitude(LongitudePolicy::Clamp);
+        assert_eq!(fe.normalize(0., 540.), Ok((0., 180.)));
+        assert_eq!(fe.normalize(0., -190.), Ok((0., -180.)));
+        assert_eq!(fe.region_number(0., 250.), crate::region_number(0., 180.));
+    }
+    #[test]
+    fn antimeridian() {
+        let east = Lookup::new().antimeridian(Antimeridian::East);
+        let west = Lookup::new().antimeridian(Antimeridian::West);
+        for &lat in [-60.5, -15.5, 0.0, 51.5, 65.5].iter() {
+            assert_eq!(east.region_number(lat, -180.), crate::region_number(lat, 180.));
+            assert_eq!(west.region_number(lat, 180.), crate::region_number(lat, -180.));
+            assert_eq!(east.region_number(lat, 179.5), crate::region_number(lat, 179.5));
+            assert_eq!(west.region_number(lat, -179.5), crate::region_number(lat, -179.5));
+        }
+        let wrap = west.longitude(LongitudePolicy::Wrap);
+        assert_eq!(wrap.region_number(0., 540.), crate::region_number(0., -180.));
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn all_lookups() {
+        let fe = Lookup::new().longitude(LongitudePolicy::Wrap);
+        let (lat, lon) = (-42.448299, 171.214005 + 720.0);
+        assert_eq!(fe.region(lat, lon), Ok("SOUTH ISLAND, NEW ZEALAND"));
+        assert_eq!(fe.seismic_region_number(lat, lon), Ok(11));
+        assert_eq!(fe.seismic_region(lat, lon), Ok("NEW ZEALAND REGION"));
+        assert_eq!(fe.geographic_region(lat, lon).unwrap().number(), 162);
+        assert_eq!(fe.region_with_style(lat, lon, NameStyle::Expanded).unwrap(), "South Island, New Zealand");
+    }
+}
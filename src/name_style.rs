@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use crate::{GeographicRegion, RegionError};
+
+/// Style of region names
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  use fe::NameStyle;
+///  let (lat, lon) = (37.5, 71.5);
+///  assert_eq!(fe::region_with_style(lat, lon, NameStyle::Original).unwrap(),
+///             "AFGHANISTAN-TAJIKISTAN BORD REG.");
+///  assert_eq!(fe::region_with_style(lat, lon, NameStyle::Expanded).unwrap(),
+///             "Afghanistan-Tajikistan Border Region");
+///  assert_eq!(fe::region_with_style(lat, lon, NameStyle::TitleCase).unwrap(),
+///             "Afghanistan-Tajikistan Bord Reg.");
+/// ```
+///
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Default)]
+pub enum NameStyle {
+    /// Upper case, abbreviated names as published, e.g. "ANDREANOF ISLANDS, ALEUTIAN IS."
+    #[default]
+    Original,
+    /// Unabbreviated, mixed case names, e.g. "Andreanof Islands, Aleutian Islands"
+    Expanded,
+    /// Published names in title case, e.g. "Andreanof Islands, Aleutian Is."
+    TitleCase,
+}
+
+/// Words kept in lower case unless they start a name
+const LOWER: [&str; 5] = ["OF", "AND", "THE", "DA", "DEL"];
+
+/// Convert an upper case region name to title case, keeping acronyms
+fn title_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, out: &mut String| {
+        if word.is_empty() {
+            return;
+        }
+        let dotted = word.trim_end_matches('.').contains('.');
+        if dotted || word == "USA" || word == "PNG." {
+            out.push_str(word);
+        } else if !out.is_empty() && LOWER.contains(&word.as_str()) {
+            out.push_str(&word.to_lowercase());
+        } else {
+            let mut prev = ' ';
+            for (i, c) in word.chars().enumerate() {
+                // Capitalize after a single letter elision, D'ENTRECASTEAUX
+                let upper = i == 0 || (prev == '\'' && i == 2);
+                if upper { out.extend(c.to_uppercase()) } else { out.extend(c.to_lowercase()) }
+                prev = c;
+            }
+        }
+        word.clear();
+    };
+    for c in name.chars() {
+        if c == ' ' || c == '-' || c == ',' {
+            flush(&mut word, &mut out);
+            out.push(c);
+        } else {
+            word.push(c);
+        }
+    }
+    flush(&mut word, &mut out);
+    out
+}
+
+impl NameStyle {
+    /// Name of geographic `region` [1, 757] in this style
+    pub fn name(&self, region: usize) -> Option<Cow<'static, str>> {
+        if region == 0 || region > crate::NAMES.len() {
+            return None;
+        }
+        Some(match self {
+            NameStyle::Original => Cow::Borrowed(crate::NAMES[region-1]),
+            NameStyle::Expanded => Cow::Borrowed(crate::NAMES_EXPANDED[region-1]),
+            NameStyle::TitleCase => Cow::Owned(title_case(crate::NAMES[region-1])),
+        })
+    }
+}
+
+impl GeographicRegion {
+    /// Region name in `style`
+    pub fn name_with_style(&self, style: NameStyle) -> Cow<'static, str> {
+        style.name(self.number()).unwrap()
+    }
+}
+
+/// Get the Flinn_Engdahl region name from a location at (`lat`,`lon`) in a given style
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let name = fe::region_with_style(35.5, -121.5, fe::NameStyle::Expanded).unwrap();
+///  assert_eq!(name, "Central California");
+/// ```
+///
+/// # Arguments
+///   - lat - Latitude  [-90, 90]
+///   - lon - Longitude [-360, 360]
+///   - style - Name Style
+///
+/// # Returns
+///   - Flinn_Engdahl Region Name
+///
+pub fn region_with_style(lat: f64, lon: f64, style: NameStyle) -> Result<Cow<'static, str>, RegionError> {
+    crate::Lookup::new().region_with_style(lat, lon, style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title() {
+        assert_eq!(title_case("ANDREANOF ISLANDS, ALEUTIAN IS."), "Andreanof Islands, Aleutian Is.");
+        assert_eq!(title_case("D'ENTRECASTEAUX ISLANDS REGION"), "D'Entrecasteaux Islands Region");
+        assert_eq!(title_case("PRIMOR'YE, RUSSIA"), "Primor'ye, Russia");
+        assert_eq!(title_case("OFF W. COAST OF N. ISLAND, N.Z."), "Off W. Coast of N. Island, N.Z.");
+        assert_eq!(title_case("THE NETHERLANDS"), "The Netherlands");
+        assert_eq!(title_case("NEAR N COAST OF NEW GUINEA, PNG."), "Near N Coast of New Guinea, PNG.");
+        assert_eq!(title_case("GEORGIA, USA"), "Georgia, USA");
+        assert_eq!(title_case("TRISTAN DA CUNHA REGION"), "Tristan da Cunha Region");
+    }
+    #[test]
+    fn styles() {
+        assert_eq!(NameStyle::default(), NameStyle::Original);
+        for style in [NameStyle::Original, NameStyle::Expanded, NameStyle::TitleCase].iter() {
+            assert_eq!(style.name(0), None);
+            assert_eq!(style.name(758), None);
+            for n in 1 ..= 757 {
+                let name = style.name(n).unwrap();
+                assert!(name.eq_ignore_ascii_case(crate::NAMES[n-1]) || *style == NameStyle::Expanded);
+            }
+        }
+        assert_eq!(NameStyle::Expanded.name(7).unwrap(), "Andreanof Islands, Aleutian Islands");
+        assert_eq!(NameStyle::Expanded.name(35).unwrap(), "Near Coast of Northern California");
+        let r = crate::geographic_region(-42.448299, 171.214005).unwrap();
+        assert_eq!(r.name_with_style(NameStyle::Expanded), "South Island, New Zealand");
+    }
+}
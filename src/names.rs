@@ -1,759 +0,0 @@
-const NAMES: [&str; 757] = [
- "CENTRAL ALASKA",
- "SOUTHERN ALASKA",
- "BERING SEA",
- "KOMANDORSKIYE OSTROVA REGION",
- "NEAR ISLANDS, ALEUTIAN ISLANDS",
- "RAT ISLANDS, ALEUTIAN ISLANDS",
- "ANDREANOF ISLANDS, ALEUTIAN IS.",
- "PRIBILOF ISLANDS, ALASKA REGION",
- "FOX ISLANDS, ALEUTIAN ISLANDS",
- "UNIMAK ISLAND REGION, ALASKA",
- "BRISTOL BAY",
- "ALASKA PENINSULA",
- "KODIAK ISLAND REGION, ALASKA",
- "KENAI PENINSULA, ALASKA",
- "GULF OF ALASKA",
- "SOUTH OF ALEUTIAN ISLANDS",
- "SOUTH OF ALASKA",
- "SOUTHERN YUKON TERRITORY, CANADA",
- "SOUTHEASTERN ALASKA",
- "OFF COAST OF SOUTHEASTERN ALASKA",
- "WEST OF VANCOUVER ISLAND",
- "QUEEN CHARLOTTE ISLANDS REGION",
- "BRITISH COLUMBIA, CANADA",
- "ALBERTA, CANADA",
- "VANCOUVER ISLAND, CANADA REGION",
- "OFF COAST OF WASHINGTON",
- "NEAR COAST OF WASHINGTON",
- "WASHINGTON-OREGON BORDER REGION",
- "WASHINGTON",
- "OFF COAST OF OREGON",
- "NEAR COAST OF OREGON",
- "OREGON",
- "WESTERN IDAHO",
- "OFF COAST OF NORTHERN CALIFORNIA",
- "NEAR COAST OF NORTHERN CALIF.",
- "NORTHERN CALIFORNIA",
- "NEVADA",
- "OFF COAST OF CALIFORNIA",
- "CENTRAL CALIFORNIA",
- "CALIFORNIA-NEVADA BORDER REGION",
- "SOUTHERN NEVADA",
- "WESTERN ARIZONA",
- "SOUTHERN CALIFORNIA",
- "CALIFORNIA-ARIZONA BORDER REGION",
- "CALIF.-BAJA CALIF. BORDER REGION",
- "W. ARIZONA-SONORA BORDER REGION",
- "OFF W. COAST OF BAJA CALIFORNIA",
- "BAJA CALIFORNIA, MEXICO",
- "GULF OF CALIFORNIA",
- "SONORA, MEXICO",
- "OFF COAST OF CENTRAL MEXICO",
- "NEAR COAST OF CENTRAL MEXICO",
- "REVILLA GIGEDO ISLANDS REGION",
- "OFF COAST OF JALISCO, MEXICO",
- "NEAR COAST OF JALISCO, MEXICO",
- "NEAR COAST OF MICHOACAN, MEXICO",
- "MICHOACAN, MEXICO",
- "NEAR COAST OF GUERRERO, MEXICO",
- "GUERRERO, MEXICO",
- "OAXACA, MEXICO",
- "CHIAPAS, MEXICO",
- "MEXICO-GUATEMALA BORDER REGION",
- "OFF COAST OF MEXICO",
- "OFF COAST OF MICHOACAN, MEXICO",
- "OFF COAST OF GUERRERO, MEXICO",
- "NEAR COAST OF OAXACA, MEXICO",
- "OFF COAST OF OAXACA, MEXICO",
- "OFF COAST OF CHIAPAS, MEXICO",
- "NEAR COAST OF CHIAPAS, MEXICO",
- "GUATEMALA",
- "NEAR COAST OF GUATEMALA",
- "HONDURAS",
- "EL SALVADOR",
- "NEAR COAST OF NICARAGUA",
- "NICARAGUA",
- "OFF COAST OF CENTRAL AMERICA",
- "OFF COAST OF COSTA RICA",
- "COSTA RICA",
- "NORTH OF PANAMA",
- "PANAMA-COSTA RICA BORDER REGION",
- "PANAMA",
- "PANAMA-COLOMBIA BORDER REGION",
- "SOUTH OF PANAMA",
- "YUCATAN PENINSULA, MEXICO",
- "CUBA REGION",
- "JAMAICA REGION",
- "HAITI REGION",
- "DOMINICAN REPUBLIC REGION",
- "MONA PASSAGE",
- "PUERTO RICO REGION",
- "VIRGIN ISLANDS",
- "LEEWARD ISLANDS",
- "BELIZE",
- "CARIBBEAN SEA",
- "WINDWARD ISLANDS",
- "NEAR NORTH COAST OF COLOMBIA",
- "NEAR COAST OF VENEZUELA",
- "TRINIDAD",
- "NORTHERN COLOMBIA",
- "LAKE MARACAIBO, VENEZUELA",
- "VENEZUELA",
- "NEAR WEST COAST OF COLOMBIA",
- "COLOMBIA",
- "OFF COAST OF ECUADOR",
- "NEAR COAST OF ECUADOR",
- "COLOMBIA-ECUADOR BORDER REGION",
- "ECUADOR",
- "OFF COAST OF NORTHERN PERU",
- "NEAR COAST OF NORTHERN PERU",
- "PERU-ECUADOR BORDER REGION",
- "NORTHERN PERU",
- "PERU-BRAZIL BORDER REGION",
- "WESTERN BRAZIL",
- "OFF COAST OF PERU",
- "NEAR COAST OF PERU",
- "CENTRAL PERU",
- "SOUTHERN PERU",
- "PERU-BOLIVIA BORDER REGION",
- "NORTHERN BOLIVIA",
- "CENTRAL BOLIVIA",
- "OFF COAST OF NORTHERN CHILE",
- "NEAR COAST OF NORTHERN CHILE",
- "NORTHERN CHILE",
- "CHILE-BOLIVIA BORDER REGION",
- "SOUTHERN BOLIVIA",
- "PARAGUAY",
- "CHILE-ARGENTINA BORDER REGION",
- "JUJUY PROVINCE, ARGENTINA",
- "SALTA PROVINCE, ARGENTINA",
- "CATAMARCA PROVINCE, ARGENTINA",
- "TUCUMAN PROVINCE, ARGENTINA",
- "SANTIAGO DEL ESTERO PROV., ARG.",
- "NORTHEASTERN ARGENTINA",
- "OFF COAST OF CENTRAL CHILE",
- "NEAR COAST OF CENTRAL CHILE",
- "CENTRAL CHILE",
- "SAN JUAN PROVINCE, ARGENTINA",
- "LA RIOJA PROVINCE, ARGENTINA",
- "MENDOZA PROVINCE, ARGENTINA",
- "SAN LUIS PROVINCE, ARGENTINA",
- "CORDOBA PROVINCE, ARGENTINA",
- "URUGUAY",
- "OFF COAST OF SOUTHERN CHILE",
- "SOUTHERN CHILE",
- "S. CHILE-ARGENTINA BORDER REGION",
- "SOUTHERN ARGENTINA",
- "TIERRA DEL FUEGO",
- "FALKLAND ISLANDS REGION",
- "DRAKE PASSAGE",
- "SCOTIA SEA",
- "SOUTH GEORGIA ISLAND REGION",
- "SOUTH GEORGIA RISE",
- "SOUTH SANDWICH ISLANDS REGION",
- "SOUTH SHETLAND ISLANDS",
- "ANTARCTIC PENINSULA",
- "SOUTHWESTERN ATLANTIC OCEAN",
- "WEDDELL SEA",
- "OFF W. COAST OF N. ISLAND, N.Z.",
- "NORTH ISLAND, NEW ZEALAND",
- "OFF E. COAST OF N. ISLAND, N.Z.",
- "OFF W. COAST OF S. ISLAND, N.Z.",
- "SOUTH ISLAND, NEW ZEALAND",
- "COOK STRAIT, NEW ZEALAND",
- "OFF E. COAST OF S. ISLAND, N.Z.",
- "NORTH OF MACQUARIE ISLAND",
- "AUCKLAND ISLANDS, N.Z. REGION",
- "MACQUARIE ISLAND REGION",
- "SOUTH OF NEW ZEALAND",
- "SAMOA ISLANDS REGION",
- "SAMOA ISLANDS",
- "SOUTH OF FIJI ISLANDS",
- "WEST OF TONGA ISLANDS",
- "TONGA ISLANDS",
- "TONGA ISLANDS REGION",
- "SOUTH OF TONGA ISLANDS",
- "NORTH OF NEW ZEALAND",
- "KERMADEC ISLANDS REGION",
- "KERMADEC ISLANDS, NEW ZEALAND",
- "SOUTH OF KERMADEC ISLANDS",
- "NORTH OF FIJI ISLANDS",
- "FIJI ISLANDS REGION",
- "FIJI ISLANDS",
- "SANTA CRUZ ISLANDS REGION",
- "SANTA CRUZ ISLANDS",
- "VANUATU ISLANDS REGION",
- "VANUATU ISLANDS",
- "NEW CALEDONIA",
- "LOYALTY ISLANDS",
- "SOUTHEAST OF LOYALTY ISLANDS",
- "NEW IRELAND REGION, P.N.G.",
- "NORTH OF SOLOMON ISLANDS",
- "NEW BRITAIN REGION, P.N.G.",
- "SOLOMON ISLANDS",
- "D'ENTRECASTEAUX ISLANDS REGION",
- "SOUTH OF SOLOMON ISLANDS",
- "IRIAN JAYA REGION, INDONESIA",
- "NEAR NORTH COAST OF IRIAN JAYA",
- "NINIGO ISLANDS REGION, P.N.G.",
- "ADMIRALTY ISLANDS REGION, P.N.G.",
- "NEAR N COAST OF NEW GUINEA, PNG.",
- "IRIAN JAYA, INDONESIA",
- "NEW GUINEA, PAPUA NEW GUINEA",
- "BISMARCK SEA",
- "ARU ISLANDS REGION, INDONESIA",
- "NEAR SOUTH COAST OF IRIAN JAYA",
- "NEAR S COAST OF NEW GUINEA, PNG.",
- "EASTERN NEW GUINEA REG., P.N.G.",
- "ARAFURA SEA",
- "W. CAROLINE ISLANDS, MICRONESIA",
- "SOUTH OF MARIANA ISLANDS",
- "SOUTHEAST OF HONSHU, JAPAN",
- "BONIN ISLANDS, JAPAN REGION",
- "VOLCANO ISLANDS, JAPAN REGION",
- "WEST OF MARIANA ISLANDS",
- "MARIANA ISLANDS REGION",
- "MARIANA ISLANDS",
- "KAMCHATKA PENINSULA, RUSSIA",
- "NEAR EAST COAST OF KAMCHATKA",
- "OFF EAST COAST OF KAMCHATKA",
- "NORTHWEST OF KURIL ISLANDS",
- "KURIL ISLANDS",
- "EAST OF KURIL ISLANDS",
- "EASTERN SEA OF JAPAN",
- "HOKKAIDO, JAPAN REGION",
- "OFF COAST OF HOKKAIDO, JAPAN",
- "NEAR WEST COAST OF HONSHU, JAPAN",
- "EASTERN HONSHU, JAPAN",
- "NEAR EAST COAST OF HONSHU, JAPAN",
- "OFF EAST COAST OF HONSHU, JAPAN",
- "NEAR S. COAST OF HONSHU, JAPAN",
- "SOUTH KOREA",
- "WESTERN HONSHU, JAPAN",
- "NEAR S. COAST OF WESTERN HONSHU",
- "NORTHWEST OF RYUKYU ISLANDS",
- "KYUSHU, JAPAN",
- "SHIKOKU, JAPAN",
- "SOUTHEAST OF SHIKOKU, JAPAN",
- "RYUKYU ISLANDS, JAPAN",
- "SOUTHEAST OF RYUKYU ISLANDS",
- "WEST OF BONIN ISLANDS",
- "PHILIPPINE SEA",
- "NEAR COAST OF SOUTHEASTERN CHINA",
- "TAIWAN REGION",
- "TAIWAN",
- "NORTHEAST OF TAIWAN",
- "SOUTHWESTERN RYUKYU ISL., JAPAN",
- "SOUTHEAST OF TAIWAN",
- "PHILIPPINE ISLANDS REGION",
- "LUZON, PHILIPPINES",
- "MINDORO, PHILIPPINES",
- "SAMAR, PHILIPPINES",
- "PALAWAN, PHILIPPINES",
- "SULU SEA",
- "PANAY, PHILIPPINES",
- "CEBU, PHILIPPINES",
- "LEYTE, PHILIPPINES",
- "NEGROS, PHILIPPINES",
- "SULU ARCHIPELAGO, PHILIPPINES",
- "MINDANAO, PHILIPPINES",
- "EAST OF PHILIPPINE ISLANDS",
- "BORNEO",
- "CELEBES SEA",
- "TALAUD ISLANDS, INDONESIA",
- "NORTH OF HALMAHERA, INDONESIA",
- "MINAHASSA PENINSULA, SULAWESI",
- "NORTHERN MOLUCCA SEA",
- "HALMAHERA, INDONESIA",
- "SULAWESI, INDONESIA",
- "SOUTHERN MOLUCCA SEA",
- "CERAM SEA",
- "BURU, INDONESIA",
- "SERAM, INDONESIA",
- "SOUTHWEST OF SUMATRA, INDONESIA",
- "SOUTHERN SUMATRA, INDONESIA",
- "JAVA SEA",
- "SUNDA STRAIT, INDONESIA",
- "JAVA, INDONESIA",
- "BALI SEA",
- "FLORES SEA",
- "BANDA SEA",
- "TANIMBAR ISLANDS REG., INDONESIA",
- "SOUTH OF JAVA, INDONESIA",
- "BALI REGION, INDONESIA",
- "SOUTH OF BALI, INDONESIA",
- "SUMBAWA REGION, INDONESIA",
- "FLORES REGION, INDONESIA",
- "SUMBA REGION, INDONESIA",
- "SAVU SEA",
- "TIMOR REGION",
- "TIMOR SEA",
- "SOUTH OF SUMBAWA, INDONESIA",
- "SOUTH OF SUMBA, INDONESIA",
- "SOUTH OF TIMOR, INDONESIA",
- "MYANMAR-INDIA BORDER REGION",
- "MYANMAR-BANGLADESH BORDER REGION",
- "MYANMAR",
- "MYANMAR-CHINA BORDER REGION",
- "NEAR SOUTH COAST OF MYANMAR",
- "SOUTHEAST ASIA",
- "HAINAN ISLAND, CHINA",
- "SOUTH CHINA SEA",
- "EASTERN KASHMIR",
- "KASHMIR-INDIA BORDER REGION",
- "KASHMIR-XIZANG BORDER REGION",
- "WESTERN XIZANG-INDIA BORDER REG.",
- "XIZANG",
- "SICHUAN, CHINA",
- "NORTHERN INDIA",
- "NEPAL-INDIA BORDER REGION",
- "NEPAL",
- "SIKKIM, INDIA",
- "BHUTAN",
- "EASTERN XIZANG-INDIA BORDER REG.",
- "SOUTHERN INDIA",
- "INDIA-BANGLADESH BORDER REGION",
- "BANGLADESH",
- "NORTHEASTERN INDIA",
- "YUNNAN, CHINA",
- "BAY OF BENGAL",
- "KYRGYZSTAN-XINJIANG BORDER REG.",
- "SOUTHERN XINJIANG, CHINA",
- "GANSU, CHINA",
- "WESTERN NEI MONGOL, CHINA",
- "KASHMIR-XINJIANG BORDER REGION",
- "QINGHAI, CHINA",
- "SOUTHWESTERN SIBERIA, RUSSIA",
- "LAKE BAYKAL REGION, RUSSIA",
- "EAST OF LAKE BAYKAL, RUSSIA",
- "EASTERN KAZAKHSTAN",
- "LAKE ISSYK-KUL REGION",
- "KAZAKHSTAN-XINJIANG BORDER REG.",
- "NORTHERN XINJIANG, CHINA",
- "RUSSIA-MONGOLIA BORDER REGION",
- "MONGOLIA",
- "URAL MOUNTAINS REGION, RUSSIA",
- "WESTERN KAZAKHSTAN",
- "EASTERN CAUCASUS",
- "CASPIAN SEA",
- "NORTHWESTERN UZBEKISTAN",
- "TURKMENISTAN",
- "TURKMENISTAN-IRAN BORDER REGION",
- "TURKMENISTAN-AFGHANISTAN BRD REG",
- "TURKEY-IRAN BORDER REGION",
- "ARMENIA-AZERBAIJAN-IRAN BORD REG",
- "NORTHWESTERN IRAN",
- "IRAN-IRAQ BORDER REGION",
- "WESTERN IRAN",
- "NORTHERN AND CENTRAL IRAN",
- "NORTHWESTERN AFGHANISTAN",
- "SOUTHWESTERN AFGHANISTAN",
- "EASTERN ARABIAN PENINSULA",
- "PERSIAN GULF",
- "SOUTHERN IRAN",
- "SOUTHWESTERN PAKISTAN",
- "GULF OF OMAN",
- "OFF COAST OF PAKISTAN",
- "UKRAINE-MOLDOVA-SW RUSSIA REGION",
- "ROMANIA",
- "BULGARIA",
- "BLACK SEA",
- "CRIMEA REGION, UKRAINE",
- "NORTHWESTERN CAUCASUS",
- "GREECE-BULGARIA BORDER REGION",
- "GREECE",
- "AEGEAN SEA",
- "TURKEY",
- "GEORGIA-ARMENIA-TURKEY BORD REG.",
- "SOUTHERN GREECE",
- "DODECANESE ISLANDS, GREECE",
- "CRETE, GREECE",
- "EASTERN MEDITERRANEAN SEA",
- "CYPRUS REGION",
- "DEAD SEA REGION",
- "JORDAN - SYRIA REGION",
- "IRAQ",
- "PORTUGAL",
- "SPAIN",
- "PYRENEES",
- "NEAR SOUTH COAST OF FRANCE",
- "CORSICA, FRANCE",
- "CENTRAL ITALY",
- "ADRIATIC SEA",
- "NORTHWESTERN BALKAN REGION",
- "WEST OF GIBRALTAR",
- "STRAIT OF GIBRALTAR",
- "BALEARIC ISLANDS, SPAIN",
- "WESTERN MEDITERRANEAN SEA",
- "SARDINIA, ITALY",
- "TYRRHENIAN SEA",
- "SOUTHERN ITALY",
- "ALBANIA",
- "GREECE-ALBANIA BORDER REGION",
- "MADEIRA ISLANDS, PORTUGAL REGION",
- "CANARY ISLANDS, SPAIN REGION",
- "MOROCCO",
- "NORTHERN ALGERIA",
- "TUNISIA",
- "SICILY, ITALY",
- "IONIAN SEA",
- "CENTRAL MEDITERRANEAN SEA",
- "NEAR COAST OF LIBYA",
- "NORTH ATLANTIC OCEAN",
- "NORTHERN MID-ATLANTIC RIDGE",
- "AZORES ISLANDS REGION",
- "AZORES ISLANDS, PORTUGAL",
- "CENTRAL MID-ATLANTIC RIDGE",
- "NORTH OF ASCENSION ISLAND",
- "ASCENSION ISLAND REGION",
- "SOUTH ATLANTIC OCEAN",
- "SOUTHERN MID-ATLANTIC RIDGE",
- "TRISTAN DA CUNHA REGION",
- "BOUVET ISLAND REGION",
- "SOUTHWEST OF AFRICA",
- "SOUTHEASTERN ATLANTIC OCEAN",
- "EASTERN GULF OF ADEN",
- "SOCOTRA REGION",
- "ARABIAN SEA",
- "LAKSHADWEEP REGION, INDIA",
- "NORTHEASTERN SOMALIA",
- "NORTH INDIAN OCEAN",
- "CARLSBERG RIDGE",
- "MALDIVE ISLANDS REGION",
- "LACCADIVE SEA",
- "SRI LANKA",
- "SOUTH INDIAN OCEAN",
- "CHAGOS ARCHIPELAGO REGION",
- "MAURITIUS - REUNION REGION",
- "SOUTHWEST INDIAN RIDGE",
- "MID-INDIAN RIDGE",
- "SOUTH OF AFRICA",
- "PRINCE EDWARD ISLANDS REGION",
- "CROZET ISLANDS REGION",
- "KERGUELEN ISLANDS REGION",
- "BROKEN RIDGE",
- "SOUTHEAST INDIAN RIDGE",
- "SOUTHERN KERGUELEN PLATEAU",
- "SOUTH OF AUSTRALIA",
- "SASKATCHEWAN, CANADA",
- "MANITOBA, CANADA",
- "HUDSON BAY",
- "ONTARIO, CANADA",
- "HUDSON STRAIT REGION, CANADA",
- "NORTHERN QUEBEC, CANADA",
- "DAVIS STRAIT",
- "LABRADOR, CANADA",
- "LABRADOR SEA",
- "SOUTHERN QUEBEC, CANADA",
- "GASPE PENINSULA, CANADA",
- "EASTERN QUEBEC, CANADA",
- "ANTICOSTI ISLAND, CANADA",
- "NEW BRUNSWICK, CANADA",
- "NOVA SCOTIA, CANADA",
- "PRINCE EDWARD ISLAND, CANADA",
- "GULF OF ST. LAWRENCE",
- "NEWFOUNDLAND, CANADA",
- "MONTANA",
- "EASTERN IDAHO",
- "HEBGEN LAKE REGION",
- "YELLOWSTONE REGION, WYOMING",
- "WYOMING",
- "NORTH DAKOTA",
- "SOUTH DAKOTA",
- "NEBRASKA",
- "MINNESOTA",
- "IOWA",
- "WISCONSIN",
- "ILLINOIS",
- "MICHIGAN",
- "INDIANA",
- "SOUTHERN ONTARIO, CANADA",
- "OHIO",
- "NEW YORK",
- "PENNSYLVANIA",
- "VERMONT - NEW HAMPSHIRE REGION",
- "MAINE",
- "SOUTHERN NEW ENGLAND",
- "GULF OF MAINE",
- "UTAH",
- "COLORADO",
- "KANSAS",
- "IOWA-MISSOURI BORDER REGION",
- "MISSOURI-KANSAS BORDER REGION",
- "MISSOURI",
- "MISSOURI-ARKANSAS BORDER REGION",
- "EASTERN MISSOURI",
- "NEW MADRID, MISSOURI REGION",
- "CAPE GIRARDEAU, MISSOURI REGION",
- "SOUTHERN ILLINOIS",
- "SOUTHERN INDIANA",
- "KENTUCKY",
- "WEST VIRGINIA",
- "VIRGINIA",
- "CHESAPEAKE BAY REGION",
- "NEW JERSEY",
- "EASTERN ARIZONA",
- "NEW MEXICO",
- "TEXAS PANHANDLE REGION",
- "WESTERN TEXAS",
- "OKLAHOMA",
- "CENTRAL TEXAS",
- "ARKANSAS-OKLAHOMA BORDER REGION",
- "ARKANSAS",
- "LOUISIANA-TEXAS BORDER REGION",
- "LOUISIANA",
- "MISSISSIPPI",
- "TENNESSEE",
- "ALABAMA",
- "WESTERN FLORIDA",
- "GEORGIA, USA",
- "FLORIDA-GEORGIA BORDER REGION",
- "SOUTH CAROLINA",
- "NORTH CAROLINA",
- "OFF EAST COAST OF UNITED STATES",
- "FLORIDA PENINSULA",
- "BAHAMA ISLANDS",
- "E. ARIZONA-SONORA BORDER REGION",
- "NEW MEXICO-CHIHUAHUA BORDER REG.",
- "TEXAS-MEXICO BORDER REGION",
- "SOUTHERN TEXAS",
- "NEAR COAST OF TEXAS",
- "CHIHUAHUA, MEXICO",
- "NORTHERN MEXICO",
- "CENTRAL MEXICO",
- "JALISCO, MEXICO",
- "VERACRUZ, MEXICO",
- "GULF OF MEXICO",
- "BAY OF CAMPECHE",
- "BRAZIL",
- "GUYANA",
- "SURINAME",
- "FRENCH GUIANA",
- "IRELAND",
- "UNITED KINGDOM",
- "NORTH SEA",
- "SOUTHERN NORWAY",
- "SWEDEN",
- "BALTIC SEA",
- "FRANCE",
- "BAY OF BISCAY",
- "THE NETHERLANDS",
- "BELGIUM",
- "DENMARK",
- "GERMANY",
- "SWITZERLAND",
- "NORTHERN ITALY",
- "AUSTRIA",
- "CZECH AND SLOVAK REPUBLICS",
- "POLAND",
- "HUNGARY",
- "NORTHWEST AFRICA",
- "SOUTHERN ALGERIA",
- "LIBYA",
- "EGYPT",
- "RED SEA",
- "WESTERN ARABIAN PENINSULA",
- "CHAD REGION",
- "SUDAN",
- "ETHIOPIA",
- "WESTERN GULF OF ADEN",
- "NORTHWESTERN SOMALIA",
- "OFF S. COAST OF NORTHWEST AFRICA",
- "CAMEROON",
- "EQUATORIAL GUINEA",
- "CENTRAL AFRICAN REPUBLIC",
- "GABON",
- "REPUBLIC OF CONGO",
- "DEMOCRATIC REPUBLIC OF CONGO",
- "UGANDA",
- "LAKE VICTORIA REGION",
- "KENYA",
- "SOUTHERN SOMALIA",
- "LAKE TANGANYIKA REGION",
- "TANZANIA",
- "NORTHWEST OF MADAGASCAR",
- "ANGOLA",
- "ZAMBIA",
- "MALAWI",
- "NAMIBIA",
- "BOTSWANA",
- "ZIMBABWE",
- "MOZAMBIQUE",
- "MOZAMBIQUE CHANNEL",
- "MADAGASCAR",
- "SOUTH AFRICA",
- "LESOTHO",
- "SWAZILAND",
- "OFF COAST OF SOUTH AFRICA",
- "NORTHWEST OF AUSTRALIA",
- "WEST OF AUSTRALIA",
- "WESTERN AUSTRALIA",
- "NORTHERN TERRITORY, AUSTRALIA",
- "SOUTH AUSTRALIA",
- "GULF OF CARPENTARIA",
- "QUEENSLAND, AUSTRALIA",
- "CORAL SEA",
- "NORTHWEST OF NEW CALEDONIA",
- "SOUTHWEST OF NEW CALEDONIA",
- "SOUTHWEST OF AUSTRALIA",
- "OFF SOUTH COAST OF AUSTRALIA",
- "NEAR COAST OF SOUTH AUSTRALIA",
- "NEW SOUTH WALES, AUSTRALIA",
- "VICTORIA, AUSTRALIA",
- "NEAR S.E. COAST OF AUSTRALIA",
- "NEAR EAST COAST OF AUSTRALIA",
- "EAST OF AUSTRALIA",
- "NORFOLK ISLAND, AUSTRALIA REGION",
- "NORTHWEST OF NEW ZEALAND",
- "BASS STRAIT, AUSTRALIA",
- "TASMANIA, AUSTRALIA REGION",
- "SOUTHEAST OF AUSTRALIA",
- "NORTH PACIFIC OCEAN",
- "HAWAIIAN ISLANDS REGION",
- "HAWAII",
- "E. CAROLINE ISLANDS, MICRONESIA",
- "MARSHALL ISLANDS REGION",
- "ENEWETAK ATOLL REG, MARSHALL IS.",
- "BIKINI ATOLL REG., MARSHALL IS.",
- "GILBERT ISLANDS, KIRIBATI REGION",
- "JOHNSTON ISLAND REGION",
- "LINE ISLANDS, KIRIBATI REGION",
- "PALMYRA ISLAND REGION, KIRIBATI",
- "KIRITIMATI REGION, KIRIBATI",
- "TUVALU REGION",
- "PHOENIX ISLANDS, KIRIBATI REGION",
- "TOKELAU ISLANDS REGION",
- "NORTHERN COOK ISLANDS",
- "COOK ISLANDS REGION",
- "SOCIETY ISLANDS REGION",
- "TUBUAI ISLANDS REGION",
- "MARQUESAS ISLANDS REGION",
- "TUAMOTU ARCHIPELAGO REGION",
- "SOUTH PACIFIC OCEAN",
- "LOMONOSOV RIDGE",
- "ARCTIC OCEAN",
- "NEAR NORTH COAST OF GREENLAND",
- "EASTERN GREENLAND",
- "ICELAND REGION",
- "ICELAND",
- "JAN MAYEN ISLAND REGION",
- "GREENLAND SEA",
- "NORTH OF SVALBARD",
- "NORWEGIAN SEA",
- "SVALBARD REGION",
- "NORTH OF FRANZ JOSEF LAND",
- "FRANZ JOSEF LAND, RUSSIA",
- "NORTHERN NORWAY",
- "BARENTS SEA",
- "NOVAYA ZEMLYA, RUSSIA",
- "KARA SEA",
- "NEAR COAST OF W. SIBERIA, RUSSIA",
- "NORTH OF SEVERNAYA ZEMLYA",
- "SEVERNAYA ZEMLYA, RUSSIA",
- "NEAR COAST OF C. SIBERIA, RUSSIA",
- "EAST OF SEVERNAYA ZEMLYA",
- "LAPTEV SEA",
- "SOUTHEASTERN SIBERIA, RUSSIA",
- "E. RUSSIA-N.E. CHINA BORDER REG.",
- "NORTHEASTERN CHINA",
- "NORTH KOREA",
- "SEA OF JAPAN",
- "PRIMOR'YE, RUSSIA",
- "SAKHALIN, RUSSIA",
- "SEA OF OKHOTSK",
- "SOUTHEASTERN CHINA",
- "YELLOW SEA",
- "OFF COAST OF EASTERN CHINA",
- "NORTH OF NEW SIBERIAN ISLANDS",
- "NEW SIBERIAN ISLANDS, RUSSIA",
- "EAST SIBERIAN SEA",
- "NEAR N. COAST OF EASTERN SIBERIA",
- "EASTERN SIBERIA, RUSSIA",
- "CHUKCHI SEA",
- "BERING STRAIT",
- "ST. LAWRENCE ISLAND, ALASKA REG.",
- "BEAUFORT SEA",
- "NORTHERN ALASKA",
- "NORTHERN YUKON TERRITORY, CANADA",
- "QUEEN ELIZABETH ISLANDS, CANADA",
- "NW TERRITORIES - NUNAVUT, CANADA",
- "WESTERN GREENLAND",
- "BAFFIN BAY",
- "BAFFIN ISLAND REGION, CANADA",
- "SOUTHEAST CENTRAL PACIFIC OCEAN",
- "SOUTHERN EAST PACIFIC RISE",
- "EASTER ISLAND REGION",
- "WEST CHILE RISE",
- "JUAN FERNANDEZ ISLANDS REGION",
- "EAST OF NORTH ISLAND, N.Z.",
- "CHATHAM ISLANDS, N.Z. REGION",
- "SOUTH OF CHATHAM ISLANDS",
- "PACIFIC-ANTARCTIC RIDGE",
- "SOUTHERN PACIFIC OCEAN",
- "EAST CENTRAL PACIFIC OCEAN",
- "CENTRAL EAST PACIFIC RISE",
- "WEST OF GALAPAGOS ISLANDS",
- "GALAPAGOS ISLANDS REGION",
- "GALAPAGOS ISLANDS, ECUADOR",
- "SOUTHWEST OF GALAPAGOS ISLANDS",
- "SOUTHEAST OF GALAPAGOS ISLANDS",
- "SOUTH OF TASMANIA",
- "WEST OF MACQUARIE ISLAND",
- "BALLENY ISLANDS REGION",
- "ANDAMAN ISLANDS, INDIA REGION",
- "NICOBAR ISLANDS, INDIA REGION",
- "OFF W COAST OF NORTHERN SUMATRA",
- "NORTHERN SUMATRA, INDONESIA",
- "MALAY PENINSULA",
- "GULF OF THAILAND",
- "SOUTHEASTERN AFGHANISTAN",
- "PAKISTAN",
- "SOUTHWESTERN KASHMIR",
- "INDIA-PAKISTAN BORDER REGION",
- "CENTRAL KAZAKHSTAN",
- "SOUTHEASTERN UZBEKISTAN",
- "TAJIKISTAN",
- "KYRGYZSTAN",
- "AFGHANISTAN-TAJIKISTAN BORD REG.",
- "HINDU KUSH REGION, AFGHANISTAN",
- "TAJIKISTAN-XINJIANG BORDER REG.",
- "NORTHWESTERN KASHMIR",
- "FINLAND",
- "NORWAY-RUSSIA BORDER REGION",
- "FINLAND-RUSSIA BORDER REGION",
- "BALTICS-BELARUS-NW RUSSIA REG.",
- "NORTHWESTERN SIBERIA, RUSSIA",
- "NORTHCENTRAL SIBERIA, RUSSIA",
- "VICTORIA LAND, ANTARCTICA",
- "ROSS SEA",
- "ANTARCTICA",
- "NORTHERN EAST PACIFIC RISE",
- "NORTH OF HONDURAS",
- "EAST OF SOUTH SANDWICH ISLANDS",
- "THAILAND",
- "LAOS",
- "CAMBODIA",
- "VIETNAM",
- "GULF OF TONGKING",
- "REYKJANES RIDGE",
- "AZORES-CAPE ST. VINCENT RIDGE",
- "OWEN FRACTURE ZONE REGION",
- "INDIAN OCEAN TRIPLE JUNCTION",
- "WESTERN INDIAN-ANTARCTIC RIDGE",
- "WESTERN SAHARA",
- "MAURITANIA",
- "MALI",
- "SENGAL - GAMBIA REGION",
- "GUINEA REGION",
- "SIERRA LEONE",
- "LIBERIA REGION",
- "COTE D'IVOIRE",
- "BURKINA FASO",
- "GHANA",
- "BENIN - TOGO REGION",
- "NIGER",
- "NIGERIA",
- "SOUTHEAST OF EASTER ISLAND",
- "GALAPAGOS TRIPLE JUNCTION REGION",
-];
\ No newline at end of file
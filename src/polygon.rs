@@ -0,0 +1,268 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{RegionError, Regionalization};
+use crate::cells::CellGrid;
+
+/// Polygon outline of part of a region
+///
+/// Coordinates are (lat, lon) vertices in degrees on the 1 degree grid.
+/// Rings are closed, with the first vertex repeated at the end; the exterior
+/// ring is counter-clockwise and holes are clockwise.
+///
+/// Regions are cut at the antimeridian, so a region spanning longitude 180
+/// is made of at least two polygons.  Polygons touching a pole carry an
+/// edge along latitude +/- 90.
+#[derive(Debug,Clone,PartialEq)]
+pub struct Polygon {
+    /// Outer boundary
+    pub exterior: Vec<(f64, f64)>,
+    /// Inner boundaries
+    pub holes: Vec<Vec<(f64, f64)>>,
+}
+
+// Directions in counter-clockwise order, a left turn is the next direction
+const EAST: u8 = 0;
+const NORTH: u8 = 1;
+const WEST: u8 = 2;
+const SOUTH: u8 = 3;
+
+type Vertex = (i16, i16);
+
+fn step((lat, lon): Vertex, dir: u8) -> Vertex {
+    match dir {
+        EAST  => (lat, lon + 1),
+        NORTH => (lat + 1, lon),
+        WEST  => (lat, lon - 1),
+        _     => (lat - 1, lon),
+    }
+}
+
+/// Twice the signed area of a ring, positive for counter-clockwise
+fn signed_area(ring: &[Vertex]) -> i64 {
+    ring.windows(2)
+        .map(|w| {
+            let ((y0, x0), (y1, x1)) = (w[0], w[1]);
+            x0 as i64 * y1 as i64 - x1 as i64 * y0 as i64
+        })
+        .sum()
+}
+
+/// Check if the point (`lat`, `lon`) is inside `ring`, point must not lie on the ring
+fn ring_contains(ring: &[Vertex], lat: f64, lon: f64) -> bool {
+    let mut inside = false;
+    for w in ring.windows(2) {
+        let ((y0, x0), (y1, x1)) = ((w[0].0 as f64, w[0].1 as f64), (w[1].0 as f64, w[1].1 as f64));
+        if (y0 > lat) != (y1 > lat) && lon < x0 + (lat - y0) / (y1 - y0) * (x1 - x0) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Trace the boundary of `region` into closed rings
+///
+/// Each cell contributes its counter-clockwise edges that do not border
+/// another cell of the region, the antimeridian always counts as a border.
+/// Edges are chained preferring left turns so cells touching only at a
+/// corner produce separate rings.
+fn rings(grid: &CellGrid, region: usize) -> Vec<Vec<Vertex>> {
+    let inside = |lat: i16, lon: i16| grid.get(lat, lon) == Some(region);
+    let mut edges : BTreeMap<Vertex, BTreeSet<u8>> = BTreeMap::new();
+    let mut add = |v: Vertex, dir: u8| { edges.entry(v).or_default().insert(dir); };
+    for c in grid.cells(region) {
+        let (lat, lon) = (c.lat, c.lon);
+        if !inside(lat - 1, lon) {
+            add((lat, lon), EAST);
+        }
+        if lon == 179 || !inside(lat, lon + 1) {
+            add((lat, lon + 1), NORTH);
+        }
+        if !inside(lat + 1, lon) {
+            add((lat + 1, lon + 1), WEST);
+        }
+        if lon == -180 || !inside(lat, lon - 1) {
+            add((lat + 1, lon), SOUTH);
+        }
+    }
+    let mut out = vec![];
+    while let Some((&start, dirs)) = edges.iter().next() {
+        let mut dir = *dirs.iter().next().unwrap();
+        let mut ring = vec![start];
+        let mut v = start;
+        loop {
+            let dirs = edges.get_mut(&v).unwrap();
+            dirs.remove(&dir);
+            if dirs.is_empty() {
+                edges.remove(&v);
+            }
+            v = step(v, dir);
+            ring.push(v);
+            if v == start {
+                break;
+            }
+            let avail = &edges[&v];
+            dir = [(dir + 1) % 4, dir, (dir + 3) % 4].iter()
+                .copied()
+                .find(|d| avail.contains(d))
+                .unwrap();
+        }
+        out.extend(split_ring(&ring).iter().map(|r| simplify(r)));
+    }
+    out
+}
+
+/// Split a closed ring touching itself at a vertex into simple rings
+///
+/// A hole touching the outer boundary at a single corner is traced as part
+/// of the outer boundary; splitting separates the two.
+fn split_ring(ring: &[Vertex]) -> Vec<Vec<Vertex>> {
+    let mut out = vec![];
+    let mut path : Vec<Vertex> = vec![];
+    for &v in ring {
+        if let Some(p) = path.iter().position(|&u| u == v) {
+            let mut r : Vec<_> = path.drain(p..).collect();
+            r.push(v);
+            out.push(r);
+        }
+        path.push(v);
+    }
+    out
+}
+
+/// Remove vertices in the middle of straight edges
+fn simplify(ring: &[Vertex]) -> Vec<Vertex> {
+    let n = ring.len() - 1;
+    let straight = |i: usize| {
+        let (a, b) = (ring[(i + n - 1) % n], ring[(i + 1) % n]);
+        a.0 == b.0 || a.1 == b.1
+    };
+    let mut out : Vec<_> = (0 .. n).filter(|&i| !straight(i)).map(|i| ring[i]).collect();
+    out.push(out[0]);
+    out
+}
+
+fn to_f64(ring: &[Vertex]) -> Vec<(f64, f64)> {
+    ring.iter().map(|&(lat, lon)| (lat as f64, lon as f64)).collect()
+}
+
+pub(crate) fn grid_polygons(grid: &CellGrid, region: usize) -> Vec<Polygon> {
+    let (outer, holes) : (Vec<_>, Vec<_>) = rings(grid, region)
+        .into_iter()
+        .partition(|r| signed_area(r) > 0);
+    let mut polys : Vec<(Vec<Vertex>, Vec<Vec<Vertex>>)> =
+        outer.into_iter().map(|r| (r, vec![])).collect();
+    for hole in holes {
+        // The cell to the left of a hole edge is inside the region
+        let (a, b) = (hole[0], hole[1]);
+        let (dlat, dlon) = ((b.0 - a.0).signum() as f64, (b.1 - a.1).signum() as f64);
+        let lat = a.0 as f64 + 0.5 * dlat + 0.5 * dlon;
+        let lon = a.1 as f64 + 0.5 * dlon - 0.5 * dlat;
+        let owner = polys.iter_mut()
+            .filter(|(r, _)| ring_contains(r, lat, lon))
+            .min_by_key(|(r, _)| signed_area(r));
+        if let Some((_, h)) = owner {
+            h.push(hole);
+        }
+    }
+    polys.into_iter()
+        .map(|(r, h)| Polygon {
+            exterior: to_f64(&r),
+            holes: h.iter().map(|r| to_f64(r)).collect(),
+        })
+        .collect()
+}
+
+impl Regionalization {
+    /// Outline of geographic `region` as polygons, see [`polygons`](crate::polygons)
+    pub fn polygons(&self, region: usize) -> Result<Vec<Polygon>, RegionError> {
+        if self.name(region).is_none() {
+            return Err(RegionError::BadRegionNumber);
+        }
+        Ok(grid_polygons(&self.cell_grid(), region))
+    }
+}
+
+/// Outline of a geographic region as polygons
+///
+/// The 1 x 1 degree cells of the region are dissolved into one or more
+/// polygons, possibly with holes.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let polys = fe::polygons(729).unwrap(); // ANTARCTICA
+///  assert!(polys.iter().any(|p| p.exterior.contains(&(-90.0, -180.0))));
+/// ```
+///
+/// # Arguments
+///   - region - Geographic Region Number [1, 757]
+///
+/// # Returns
+///   - Polygons, empty if the region has no full cells
+///
+pub fn polygons(region: usize) -> Result<Vec<Polygon>, RegionError> {
+    Regionalization::fe1995().polygons(region)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use super::*;
+
+    fn area(ring: &[(f64, f64)]) -> f64 {
+        ring.windows(2).map(|w| w[0].1 * w[1].0 - w[1].1 * w[0].0).sum::<f64>() / 2.0
+    }
+
+    #[test]
+    fn single_cell() {
+        let grid = Regionalization::fe1995().cell_grid();
+        // Find a region made of a single cell
+        let (r, cell) = (1 ..= 757)
+            .filter_map(|r| {
+                let c : Vec<_> = grid.cells(r).collect();
+                if c.len() == 1 { Some((r, c[0])) } else { None }
+            })
+            .next()
+            .unwrap();
+        let p = grid_polygons(&grid, r);
+        assert_eq!(p.len(), 1);
+        let (s, w) = (cell.south(), cell.west());
+        assert_eq!(p[0].exterior, [(s, w), (s, w + 1.0), (s + 1.0, w + 1.0), (s + 1.0, w), (s, w)]);
+        assert!(p[0].holes.is_empty());
+    }
+    #[test]
+    fn all_regions() {
+        let grid = Regionalization::fe1995().cell_grid();
+        for r in 1 ..= 757 {
+            let n = grid.cells(r).count() as f64;
+            let polys = grid_polygons(&grid, r);
+            let mut total = 0.0;
+            for p in &polys {
+                assert!(area(&p.exterior) > 0.0, "{}", r);
+                total += area(&p.exterior);
+                for h in p.holes.iter().chain(std::iter::once(&p.exterior)) {
+                    assert_eq!(h.first(), h.last());
+                    // Rings are simple, no repeated vertices
+                    let uniq : BTreeSet<_> = h[1..].iter().map(|&(a, b)| (a as i32, b as i32)).collect();
+                    assert_eq!(uniq.len(), h.len() - 1, "{}", r);
+                }
+                for h in &p.holes {
+                    assert!(area(h) < 0.0, "{}", r);
+                    total += area(h);
+                }
+            }
+            assert_eq!(total, n, "{}", r);
+        }
+    }
+    #[test]
+    fn antimeridian() {
+        let polys = crate::polygons(729).unwrap();
+        for p in &polys {
+            for &(lat, lon) in &p.exterior {
+                assert!((-180.0 ..= 180.0).contains(&lon));
+                assert!((-90.0 ..= 90.0).contains(&lat));
+            }
+        }
+        assert!(polys.len() >= 2 || polys[0].exterior.iter().any(|v| v.1 == 180.0));
+        assert_eq!(crate::polygons(0), Err(RegionError::BadRegionNumber));
+    }
+}
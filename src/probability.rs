@@ -0,0 +1,185 @@
+use crate::{Lookup, RegionError};
+use crate::geometry;
+
+/// Horizontal location uncertainty, taken as one standard deviation of a
+/// bivariate normal distribution
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub enum Uncertainty {
+    /// Circular uncertainty of radius in km
+    Circle {
+        /// Radius, km
+        radius: f64,
+    },
+    /// Elliptical uncertainty
+    Ellipse {
+        /// Semi-major axis, km
+        semi_major: f64,
+        /// Semi-minor axis, km
+        semi_minor: f64,
+        /// Azimuth of the major axis, degrees clockwise from north
+        azimuth: f64,
+    },
+}
+
+/// Probability of a location falling in a region
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct RegionProbability {
+    /// Geographic Region Number
+    pub region: usize,
+    /// Probability mass in [0, 1]
+    pub probability: f64,
+}
+
+/// Number of standard deviations sampled on each side of the location
+const SIGMAS: f64 = 3.5;
+/// Number of samples on each side of the location along each axis
+///
+/// Samples sit at the midpoints of the steps, so none falls on the axes
+/// and a location on a cell corner splits evenly between the cells
+const STEPS: i32 = 20;
+
+impl Lookup {
+    /// Get the probability of each region given a location and its uncertainty,
+    /// see [`region_probabilities`](crate::region_probabilities)
+    pub fn region_probabilities(&self, lat: f64, lon: f64, unc: Uncertainty)
+                                -> Result<Vec<RegionProbability>, RegionError> {
+        let (lat, lon) = self.normalize(lat, lon)?;
+        let (a, b, az) = match unc {
+            Uncertainty::Circle { radius } => (radius, radius, 0.0),
+            Uncertainty::Ellipse { semi_major, semi_minor, azimuth } => (semi_major, semi_minor, azimuth),
+        };
+        let ok = |v: f64| v.is_finite() && v >= 0.0;
+        if !ok(a) || !ok(b) || !az.is_finite() {
+            return Err(RegionError::BadTolerance);
+        }
+        let mut mass = vec![0.0; crate::NAMES.len() + 1];
+        let (sin, cos) = az.to_radians().sin_cos();
+        let h = SIGMAS / STEPS as f64;
+        for i in -STEPS .. STEPS {
+            for j in -STEPS .. STEPS {
+                // Standardized coordinates along the major (u) and minor (v) axes
+                let (u, v) = ((i as f64 + 0.5) * h, (j as f64 + 0.5) * h);
+                let w = (-(u * u + v * v) / 2.0).exp();
+                let east = u * a * sin + v * b * cos;
+                let north = u * a * cos - v * b * sin;
+                let dist = east.hypot(north);
+                let (plat, plon) = if dist > 0.0 {
+                    geometry::destination(lat, lon, dist, east / dist, north / dist)
+                } else {
+                    (lat, lon)
+                };
+                // Samples on the antimeridian stay on the side of the location,
+                // then follow the antimeridian setting in the lookup
+                let plon = match (plon.abs() == 180.0, lon.abs() == 180.0) {
+                    (true, true) => lon,
+                    (true, false) => if lon > 0.0 { 180.0 } else { -180.0 },
+                    (false, _) => plon,
+                };
+                mass[self.region_number(plat, plon)?] += w;
+            }
+        }
+        let total : f64 = mass.iter().sum();
+        let mut out : Vec<_> = mass.iter().enumerate()
+            .filter(|(_, &m)| m > 0.0)
+            .map(|(region, &m)| RegionProbability { region, probability: m / total })
+            .collect();
+        out.sort_by(|a, b| b.probability.partial_cmp(&a.probability).unwrap().then(a.region.cmp(&b.region)));
+        Ok(out)
+    }
+}
+
+/// Get the probability of each region given a location and its horizontal uncertainty
+///
+/// The uncertainty is treated as one standard deviation of a bivariate normal
+/// distribution centered on the location.  The distribution is sampled out to
+/// 3.5 standard deviations and each sample looked up in the region tables.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  use fe::Uncertainty;
+///  // Near the corner of four cells
+///  let p = fe::region_probabilities(42.0, -73.0, Uncertainty::Circle { radius: 20.0 }).unwrap();
+///  assert!(p.len() > 1);
+///  let total : f64 = p.iter().map(|r| r.probability).sum();
+///  assert!((total - 1.0).abs() < 1e-9);
+///
+///  let p = fe::region_probabilities(-89.5, 0.0, Uncertainty::Ellipse {
+///      semi_major: 10.0, semi_minor: 5.0, azimuth: 30.0 }).unwrap();
+///  assert_eq!(p[0].region, 729);
+///  assert_eq!(p[0].probability, 1.0);
+/// ```
+///
+/// # Arguments
+///   - lat - Latitude  [-90, 90]
+///   - lon - Longitude [-360, 360]
+///   - unc - Horizontal uncertainty
+///
+/// # Returns
+///   - Regions and probabilities, most probable first
+///
+pub fn region_probabilities(lat: f64, lon: f64, unc: Uncertainty) -> Result<Vec<RegionProbability>, RegionError> {
+    Lookup::new().region_probabilities(lat, lon, unc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_uncertainty() {
+        let p = region_probabilities(42.0, -73.0, Uncertainty::Circle { radius: 0.0 }).unwrap();
+        assert_eq!(p, [RegionProbability { region: crate::region_number(42.0, -73.0).unwrap(), probability: 1.0 }]);
+    }
+    #[test]
+    fn symmetric_corner() {
+        // Four distinct regions meet at 52 S, 71 W, a circle splits evenly between them
+        let (lat, lon) = (-52.0, -71.0);
+        let p = region_probabilities(lat, lon, Uncertainty::Circle { radius: 5.0 }).unwrap();
+        let mut regions : Vec<_> = [(0.5, 0.5), (0.5, -0.5), (-0.5, 0.5), (-0.5, -0.5)].iter()
+            .map(|(a, b)| crate::region_number(lat + a, lon + b).unwrap())
+            .collect();
+        let mut found : Vec<_> = p.iter().map(|x| x.region).collect();
+        regions.sort_unstable();
+        found.sort_unstable();
+        assert_eq!(found, regions);
+        for x in &p {
+            assert!((x.probability - 0.25).abs() < 1e-3, "{:?}", p);
+        }
+    }
+    #[test]
+    fn antimeridian() {
+        use crate::Antimeridian;
+        // A degenerate north-south ellipse keeps every sample on the antimeridian
+        let (lat, unc) = (-15.5, Uncertainty::Ellipse { semi_major: 20.0, semi_minor: 0.0, azimuth: 0.0 });
+        let east = Lookup::new().antimeridian(Antimeridian::East);
+        let west = Lookup::new().antimeridian(Antimeridian::West);
+        let given = Lookup::new();
+        let pe = east.region_probabilities(lat, 180.0, unc).unwrap();
+        let pw = west.region_probabilities(lat, -180.0, unc).unwrap();
+        assert_eq!(pe[0].region, crate::region_number(lat, 180.0).unwrap());
+        assert_eq!(pw[0].region, crate::region_number(lat, -180.0).unwrap());
+        assert_ne!(pe, pw);
+        assert_eq!(given.region_probabilities(lat, 180.0, unc).unwrap(), pe);
+        assert_eq!(given.region_probabilities(lat, -180.0, unc).unwrap(), pw);
+        assert_eq!(east.region_probabilities(lat, -180.0, unc).unwrap(), pe);
+        assert_eq!(west.region_probabilities(lat, 180.0, unc).unwrap(), pw);
+    }
+    #[test]
+    fn ellipse_orientation() {
+        // North-south ellipse crossing the cell edge at 41 N vs east-west ellipse that does not
+        let (lat, lon) = (41.05, -72.5);
+        assert_ne!(crate::region_number(lat, lon), crate::region_number(40.95, lon));
+        let ns = region_probabilities(lat, lon, Uncertainty::Ellipse { semi_major: 20.0, semi_minor: 0.1, azimuth: 0.0 }).unwrap();
+        let ew = region_probabilities(lat, lon, Uncertainty::Ellipse { semi_major: 20.0, semi_minor: 0.1, azimuth: 90.0 }).unwrap();
+        let here = crate::region_number(lat, lon).unwrap();
+        let p = |v: &[RegionProbability]| v.iter().find(|x| x.region == here).unwrap().probability;
+        assert!(p(&ns) < p(&ew));
+    }
+    #[test]
+    fn bad_uncertainty() {
+        assert_eq!(region_probabilities(0., 0., Uncertainty::Circle { radius: -1.0 }), Err(RegionError::BadTolerance));
+        assert_eq!(region_probabilities(0., 0., Uncertainty::Ellipse { semi_major: 1.0, semi_minor: 1.0, azimuth: f64::NAN }),
+                   Err(RegionError::BadTolerance));
+        assert_eq!(region_probabilities(0., 400., Uncertainty::Circle { radius: 1.0 }), Err(RegionError::BadLongitude));
+    }
+}
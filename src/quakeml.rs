@@ -0,0 +1,597 @@
+//! Flinn-Engdahl region descriptions in QuakeML 1.2 documents
+//!
+//! An event's region is given by an `EventDescription`, written as a
+//! `description` element of the event with type `Flinn-Engdahl region`.
+//! Documents are scanned for element positions only; every change is
+//! spliced into the original text, so anything not changed is written back
+//! byte for byte, comments, namespaces and formatting included.
+
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::ops::Range;
+
+use crate::{Lookup, RegionError};
+
+/// Type of the event description holding the Flinn-Engdahl region
+pub const FLINN_ENGDAHL_TYPE: &str = "Flinn-Engdahl region";
+
+/// Error stopping a QuakeML document from being annotated
+#[derive(Debug)]
+pub enum QuakeMlError {
+    /// Reading the input or writing the output failed
+    Io(io::Error),
+    /// The document is not well formed XML or not QuakeML
+    Xml {
+        /// Line number, 1-based
+        line: usize,
+        /// Description of the problem
+        message: String,
+    },
+}
+
+impl fmt::Display for QuakeMlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuakeMlError::Io(err) => write!(f, "{}", err),
+            QuakeMlError::Xml { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for QuakeMlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            QuakeMlError::Io(err) => Some(err),
+            QuakeMlError::Xml { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for QuakeMlError {
+    fn from(err: io::Error) -> Self {
+        QuakeMlError::Io(err)
+    }
+}
+
+/// Problem with the region description of a single event
+#[derive(Debug,Clone,PartialEq)]
+pub enum EventProblem {
+    /// The event has no Flinn-Engdahl region description
+    Missing,
+    /// The event's description differs from the computed region
+    Mismatch {
+        /// Region name in the document
+        found: String,
+        /// Region name of the preferred origin
+        expected: &'static str,
+    },
+    /// The event has no origin, or none with its preferredOriginID
+    NoOrigin,
+    /// The origin has no latitude or longitude value
+    MissingCoordinate(&'static str),
+    /// The latitude or longitude is not a number
+    Unparsable(&'static str, String),
+    /// The location is out of range
+    Region(RegionError),
+}
+
+/// An event whose region description is missing, wrong or cannot be computed
+#[derive(Debug,Clone,PartialEq)]
+pub struct EventError {
+    /// publicID of the event
+    pub event: String,
+    /// Line number of the event, 1-based
+    pub line: usize,
+    /// What is wrong with it
+    pub problem: EventProblem,
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: event {}: ", self.line, self.event)?;
+        match &self.problem {
+            EventProblem::Missing => write!(f, "no {} description", FLINN_ENGDAHL_TYPE),
+            EventProblem::Mismatch { found, expected } =>
+                write!(f, "{} is {:?}, expected {:?}", FLINN_ENGDAHL_TYPE, found, expected),
+            EventProblem::NoOrigin => write!(f, "no preferred origin"),
+            EventProblem::MissingCoordinate(what) => write!(f, "origin has no {}", what),
+            EventProblem::Unparsable(what, value) => write!(f, "invalid {} {:?}", what, value),
+            EventProblem::Region(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// What to do with the region descriptions of events
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Default)]
+pub enum QuakeMlMode {
+    /// Insert missing descriptions; report existing ones that differ
+    #[default]
+    Insert,
+    /// Insert missing descriptions and correct existing ones that differ
+    Replace,
+    /// Change nothing; report missing descriptions and ones that differ
+    Verify,
+}
+
+/// Outcome of annotating a QuakeML document
+#[derive(Debug,Clone,Default,PartialEq)]
+pub struct QuakeMlReport {
+    /// Number of events
+    pub events: usize,
+    /// Descriptions inserted
+    pub inserted: usize,
+    /// Descriptions corrected
+    pub replaced: usize,
+    /// Events with a missing or differing description, or without a usable origin
+    pub errors: Vec<EventError>,
+}
+
+/// An element of a document, by byte offsets
+#[derive(Debug)]
+struct Element {
+    /// Name as written, with any namespace prefix
+    name: String,
+    /// Start tag, or the whole of an empty element
+    tag: Range<usize>,
+    /// Content between the start and end tags
+    content: Range<usize>,
+    /// Whole element, from the start of the start tag to the end of the end tag
+    span: Range<usize>,
+    children: Vec<Element>,
+}
+
+/// Line number of a byte offset
+fn line_of(doc: &str, offset: usize) -> usize {
+    doc[.. offset].matches('\n').count() + 1
+}
+
+/// Find `pat` at or after `from`, returning the offset just past it
+fn skip_past(doc: &str, from: usize, pat: &str, what: &str) -> Result<usize, QuakeMlError> {
+    doc[from ..].find(pat)
+        .map(|i| from + i + pat.len())
+        .ok_or_else(|| QuakeMlError::Xml { line: line_of(doc, from), message: format!("unterminated {}", what) })
+}
+
+/// End of a tag starting at `from`, just past the `>`, skipping quoted attribute values
+fn tag_end(doc: &str, from: usize) -> Result<usize, QuakeMlError> {
+    let mut quote = None;
+    for (i, c) in doc[from ..].char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Ok(from + i + 1),
+            _ => {},
+        }
+    }
+    Err(QuakeMlError::Xml { line: line_of(doc, from), message: "unterminated tag".to_string() })
+}
+
+/// Parse the elements of a document
+fn parse(doc: &str) -> Result<Vec<Element>, QuakeMlError> {
+    let mut roots = vec![];
+    let mut stack : Vec<Element> = vec![];
+    let mut pos = 0;
+    while let Some(i) = doc[pos ..].find('<') {
+        let start = pos + i;
+        let rest = &doc[start ..];
+        pos = if rest.starts_with("<!--") {
+            skip_past(doc, start, "-->", "comment")?
+        } else if rest.starts_with("<![CDATA[") {
+            skip_past(doc, start, "]]>", "CDATA section")?
+        } else if rest.starts_with("<?") {
+            skip_past(doc, start, "?>", "processing instruction")?
+        } else if rest.starts_with("<!") {
+            tag_end(doc, start)?
+        } else if let Some(rest) = rest.strip_prefix("</") {
+            let end = tag_end(doc, start)?;
+            let name = rest[.. end - start - 3].trim();
+            let mut elem = match stack.pop() {
+                Some(elem) if elem.name == name => elem,
+                _ => return Err(QuakeMlError::Xml { line: line_of(doc, start),
+                                                    message: format!("unexpected end tag </{}>", name) }),
+            };
+            elem.content.end = start;
+            elem.span.end = end;
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(elem),
+                None => roots.push(elem),
+            }
+            end
+        } else {
+            let end = tag_end(doc, start)?;
+            let name : String = rest[1 ..].chars()
+                .take_while(|c| !c.is_whitespace() && *c != '/' && *c != '>')
+                .collect();
+            let empty = doc[.. end - 1].ends_with('/');
+            let elem = Element { name, tag: start .. end, content: end .. end, span: start .. end, children: vec![] };
+            match (empty, stack.last_mut()) {
+                (false, _) => stack.push(elem),
+                (true, Some(parent)) => parent.children.push(elem),
+                (true, None) => roots.push(elem),
+            }
+            end
+        };
+    }
+    if let Some(elem) = stack.last() {
+        return Err(QuakeMlError::Xml { line: line_of(doc, elem.tag.start),
+                                       message: format!("<{}> is not closed", elem.name) });
+    }
+    Ok(roots)
+}
+
+/// Replace entity and character references
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('&') {
+        out += &rest[.. i];
+        rest = &rest[i ..];
+        let end = match rest.find(';') {
+            Some(end) => end,
+            None => break,
+        };
+        let c = match &rest[1 .. end] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            r => r.strip_prefix("#x").map(|h| u32::from_str_radix(h, 16))
+                .or_else(|| r.strip_prefix('#').map(|d| d.parse()))
+                .and_then(|v| v.ok())
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1 ..];
+            },
+            None => {
+                out.push('&');
+                rest = &rest[1 ..];
+            },
+        }
+    }
+    out + rest
+}
+
+/// Escape text content
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+impl Element {
+    /// Name without a namespace prefix
+    fn local(&self) -> &str {
+        self.name.rsplit(':').next().unwrap()
+    }
+    /// Namespace prefix with its colon, if any
+    fn prefix(&self) -> &str {
+        &self.name[.. self.name.len() - self.local().len()]
+    }
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |e| e.local() == name)
+    }
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|e| e.local() == name)
+    }
+    /// Text content, trimmed, with CDATA sections unwrapped
+    fn text(&self, doc: &str) -> String {
+        let raw = doc[self.content.clone()].trim();
+        match raw.strip_prefix("<![CDATA[").and_then(|r| r.strip_suffix("]]>")) {
+            Some(cdata) => cdata.trim().to_string(),
+            None => unescape(raw),
+        }
+    }
+    /// Value of an attribute
+    fn attribute(&self, doc: &str, name: &str) -> Option<String> {
+        let tag = &doc[self.tag.clone()];
+        let mut rest = &tag[1 + self.name.len() ..];
+        while let Some(eq) = rest.find('=') {
+            let key = rest[.. eq].trim();
+            let value = rest[eq + 1 ..].trim_start();
+            let q = value.chars().next()?;
+            let end = value[1 ..].find(q)?;
+            if key == name {
+                return Some(unescape(&value[1 .. 1 + end]));
+            }
+            rest = &value[end + 2 ..];
+        }
+        None
+    }
+    /// Events within this element
+    fn events<'a>(&'a self, out: &mut Vec<&'a Element>) {
+        for e in &self.children {
+            if e.local() == "event" {
+                out.push(e);
+            } else {
+                e.events(out);
+            }
+        }
+    }
+}
+
+/// Adds or checks Flinn-Engdahl region descriptions of QuakeML events
+///
+/// The region of each event is that of its preferred origin, or its only
+/// origin if it has no `preferredOriginID`.  Depending on the
+/// [`QuakeMlMode`], missing descriptions are inserted after any existing
+/// descriptions of the event, differing ones corrected, or both only
+/// reported.  Region names are compared ignoring case.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let doc = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <q:quakeml xmlns:q="http://quakeml.org/xmlns/quakeml/1.2" xmlns="http://quakeml.org/xmlns/bed/1.2">
+///   <eventParameters publicID="smi:local/catalog">
+///     <event publicID="smi:local/event/1">
+///       <preferredOriginID>smi:local/origin/1</preferredOriginID>
+///       <origin publicID="smi:local/origin/1">
+///         <latitude><value>-42.448299</value></latitude>
+///         <longitude><value>171.214005</value></longitude>
+///       </origin>
+///     </event>
+///   </eventParameters>
+/// </q:quakeml>
+/// "#;
+///  let (out, report) = fe::QuakeMlAnnotator::new().annotate_str(doc).unwrap();
+///  assert_eq!(report.inserted, 1);
+///  assert!(out.contains("<event publicID=\"smi:local/event/1\">\n      \
+///                        <description><text>SOUTH ISLAND, NEW ZEALAND</text>\
+///                        <type>Flinn-Engdahl region</type></description>\n      \
+///                        <preferredOriginID>"));
+///
+///  let verify = fe::QuakeMlAnnotator::new().mode(fe::QuakeMlMode::Verify);
+///  let (same, report) = verify.annotate_str(&out).unwrap();
+///  assert_eq!((same, report.errors.len()), (out, 0));
+/// ```
+///
+#[derive(Debug,Clone,Default)]
+pub struct QuakeMlAnnotator {
+    mode: QuakeMlMode,
+    lookup: Lookup,
+}
+
+impl QuakeMlAnnotator {
+    /// Default annotator, inserting missing descriptions
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set what to do with the descriptions
+    pub fn mode(mut self, mode: QuakeMlMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// Set the lookup used to find regions
+    pub fn lookup(mut self, lookup: Lookup) -> Self {
+        self.lookup = lookup;
+        self
+    }
+    /// Region name of an event's preferred origin
+    fn event_region(&self, doc: &str, event: &Element) -> Result<&'static str, EventProblem> {
+        let origin = match event.child("preferredOriginID") {
+            Some(id) => {
+                let id = id.text(doc);
+                event.children_named("origin")
+                    .find(|o| o.attribute(doc, "publicID").as_deref() == Some(id.as_str()))
+            },
+            None if event.children_named("origin").count() == 1 => event.child("origin"),
+            None => None,
+        };
+        let origin = origin.ok_or(EventProblem::NoOrigin)?;
+        let value = |what| {
+            let v = origin.child(what).and_then(|c| c.child("value"))
+                .ok_or(EventProblem::MissingCoordinate(what))?
+                .text(doc);
+            v.parse::<f64>().map_err(|_| EventProblem::Unparsable(what, v))
+        };
+        let (lat, lon) = (value("latitude")?, value("longitude")?);
+        self.lookup.region(lat, lon).map_err(EventProblem::Region)
+    }
+    /// Edit, if any, for the description of an event
+    fn event_edit(&self, doc: &str, event: &Element, report: &mut QuakeMlReport)
+                  -> Result<Option<(Range<usize>, String)>, EventProblem> {
+        let name = self.event_region(doc, event)?;
+        let fe = event.children_named("description")
+            .find(|d| d.child("type").map(|t| t.text(doc)).as_deref() == Some(FLINN_ENGDAHL_TYPE));
+        let p = event.prefix();
+        match (fe, self.mode) {
+            (None, QuakeMlMode::Verify) => Err(EventProblem::Missing),
+            (None, _) => {
+                // After the last description, or as the first child, indented like the first child
+                let at = event.children_named("description").last().map(|d| d.span.end)
+                    .unwrap_or(event.tag.end);
+                let first = event.children.first().map(|c| c.tag.start).unwrap_or(event.content.end);
+                let indent = &doc[event.tag.end .. first];
+                let indent = &indent[.. indent.len() - indent.trim_start().len()];
+                report.inserted += 1;
+                Ok(Some((at .. at, format!("{}<{}description><{}text>{}</{}text><{}type>{}</{}type></{}description>",
+                                           indent, p, p, escape(name), p, p, FLINN_ENGDAHL_TYPE, p, p))))
+            },
+            (Some(d), mode) => {
+                let text = d.child("text");
+                let found = text.map(|t| t.text(doc)).unwrap_or_default();
+                if found.eq_ignore_ascii_case(name) {
+                    return Ok(None);
+                }
+                match (mode, text) {
+                    (QuakeMlMode::Replace, Some(t)) if t.span == t.tag => {
+                        // Empty <text/>, replaced as a whole
+                        report.replaced += 1;
+                        Ok(Some((t.span.clone(), format!("<{}text>{}</{}text>", p, escape(name), p))))
+                    },
+                    (QuakeMlMode::Replace, Some(t)) => {
+                        report.replaced += 1;
+                        Ok(Some((t.content.clone(), escape(name))))
+                    },
+                    (QuakeMlMode::Replace, None) => {
+                        report.replaced += 1;
+                        Ok(Some((d.tag.end .. d.tag.end, format!("<{}text>{}</{}text>", p, escape(name), p))))
+                    },
+                    _ => Err(EventProblem::Mismatch { found, expected: name }),
+                }
+            },
+        }
+    }
+    /// Annotate a QuakeML document, returning the new document
+    ///
+    /// Stops only if the document is malformed; events that cannot be
+    /// annotated, or whose descriptions differ, are listed in the report
+    pub fn annotate_str(&self, doc: &str) -> Result<(String, QuakeMlReport), QuakeMlError> {
+        let roots = parse(doc)?;
+        let root = match roots.iter().find(|e| e.local() == "quakeml") {
+            Some(root) => root,
+            None => return Err(QuakeMlError::Xml { line: 1, message: "not a QuakeML document".to_string() }),
+        };
+        let mut events = vec![];
+        root.events(&mut events);
+        let mut report = QuakeMlReport { events: events.len(), ..Default::default() };
+        let mut edits = vec![];
+        for event in events {
+            match self.event_edit(doc, event, &mut report) {
+                Ok(edit) => edits.extend(edit),
+                Err(problem) => report.errors.push(EventError {
+                    event: event.attribute(doc, "publicID").unwrap_or_default(),
+                    line: line_of(doc, event.tag.start),
+                    problem,
+                }),
+            }
+        }
+        let mut out = String::with_capacity(doc.len() + 100 * edits.len());
+        let mut pos = 0;
+        for (range, text) in edits {
+            out += &doc[pos .. range.start];
+            out += &text;
+            pos = range.end;
+        }
+        out += &doc[pos ..];
+        Ok((out, report))
+    }
+    /// Annotate a QuakeML document from `input`, writing it to `output`
+    pub fn annotate<R: Read, W: Write>(&self, mut input: R, mut output: W) -> Result<QuakeMlReport, QuakeMlError> {
+        let mut doc = String::new();
+        input.read_to_string(&mut doc)?;
+        let (out, report) = self.annotate_str(&doc)?;
+        output.write_all(out.as_bytes())?;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<q:quakeml xmlns:q="http://quakeml.org/xmlns/quakeml/1.2" xmlns="http://quakeml.org/xmlns/bed/1.2">
+  <!-- <event> in a comment -->
+  <eventParameters publicID='smi:local/catalog'>
+    <event publicID="smi:local/event/1">
+      <description><text>Near Westerly, R.I.</text><type>nearest cities</type></description>
+      <origin publicID="smi:local/origin/1a"><latitude><value>0.5</value></latitude><longitude><value>0.5</value></longitude></origin>
+      <origin publicID="smi:local/origin/1b">
+        <latitude><value> 41.440971 </value><uncertainty>1</uncertainty></latitude>
+        <longitude><value>-71.502289</value></longitude>
+      </origin>
+      <preferredOriginID>smi:local/origin/1b</preferredOriginID>
+    </event>
+    <event publicID="smi:local/event/2">
+      <description>
+        <text>south island, new zealand</text>
+        <type>Flinn-Engdahl region</type>
+      </description>
+      <origin publicID="smi:local/origin/2"><latitude><value>-42.448299</value></latitude><longitude><value>171.214005</value></longitude></origin>
+    </event>
+    <event publicID="smi:local/event/3">
+      <description><text>GULF &amp; COAST</text><type>Flinn-Engdahl region</type></description>
+      <origin publicID="smi:local/origin/3"><latitude><value>0.5</value></latitude><longitude><value>0.5</value></longitude></origin>
+    </event>
+    <event publicID="smi:local/event/4"><origin publicID="smi:local/origin/4"><latitude><value>95</value></latitude><longitude><value>0</value></longitude></origin></event>
+    <event publicID="smi:local/event/5"/>
+  </eventParameters>
+</q:quakeml>
+"#;
+
+    fn problems(report: &QuakeMlReport) -> Vec<String> {
+        report.errors.iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn insert() {
+        let (out, report) = QuakeMlAnnotator::new().annotate_str(DOC).unwrap();
+        assert_eq!((report.events, report.inserted, report.replaced), (5, 1, 0));
+        assert_eq!(problems(&report), [
+            "line 21: event smi:local/event/3: Flinn-Engdahl region is \"GULF & COAST\", \
+             expected \"OFF S. COAST OF NORTHWEST AFRICA\"",
+            "line 25: event smi:local/event/4: latitude out of range [-90, 90]",
+            "line 26: event smi:local/event/5: no preferred origin",
+        ]);
+        let inserted = "      <description><text>Near Westerly, R.I.</text><type>nearest cities</type></description>\
+                        \n      <description><text>SOUTHERN NEW ENGLAND</text>\
+                        <type>Flinn-Engdahl region</type></description>\n";
+        assert!(out.contains(inserted), "{}", out);
+        assert_eq!(out.replacen(inserted, "", 1),
+                   DOC.replacen("      <description><text>Near Westerly, R.I.</text><type>nearest cities</type></description>\n", "", 1));
+
+        // Inserted descriptions verify; a second pass changes nothing
+        let (again, report) = QuakeMlAnnotator::new().annotate_str(&out).unwrap();
+        assert_eq!((again, report.inserted, report.errors.len()), (out, 0, 3));
+    }
+    #[test]
+    fn replace_and_verify() {
+        let fe = QuakeMlAnnotator::new().mode(QuakeMlMode::Replace);
+        let (out, report) = fe.annotate_str(DOC).unwrap();
+        assert_eq!((report.inserted, report.replaced, report.errors.len()), (1, 1, 2));
+        assert!(out.contains("<text>OFF S. COAST OF NORTHWEST AFRICA</text><type>Flinn-Engdahl region</type>"));
+        assert!(!out.contains("GULF"));
+
+        let fe = QuakeMlAnnotator::new().mode(QuakeMlMode::Verify);
+        let mut out = vec![];
+        let report = fe.annotate(DOC.as_bytes(), &mut out).unwrap();
+        assert_eq!(out, DOC.as_bytes());
+        assert_eq!(report.errors[0].problem, EventProblem::Missing);
+        assert_eq!(report.errors.len(), 4);
+    }
+    #[test]
+    fn empty_elements() {
+        let doc = r#"<quakeml>
+  <eventParameters>
+    <event publicID="smi:local/event/1">
+      <description/>
+      <origin><latitude><value>0.5</value></latitude><longitude><value>0.5</value></longitude></origin>
+    </event>
+    <event publicID="smi:local/event/2">
+      <description><text>x</text></description >
+      <origin><latitude><value>0.5</value></latitude><longitude><value>0.5</value></longitude></origin>
+    </event>
+    <event publicID="smi:local/event/3">
+      <description><text/><type>Flinn-Engdahl region</type></description>
+      <origin><latitude><value>0.5</value></latitude><longitude><value>0.5</value></longitude></origin>
+    </event>
+  </eventParameters>
+</quakeml>
+"#;
+        let fe = "<description><text>OFF S. COAST OF NORTHWEST AFRICA</text><type>Flinn-Engdahl region</type></description>";
+        let expected = doc
+            .replacen("<description/>\n", &format!("<description/>\n      {}\n", fe), 1)
+            .replacen("</description >\n", &format!("</description >\n      {}\n", fe), 1)
+            .replacen("<description><text/>", "<description><text>OFF S. COAST OF NORTHWEST AFRICA</text>", 1);
+        let (out, report) = QuakeMlAnnotator::new().mode(QuakeMlMode::Replace).annotate_str(doc).unwrap();
+        assert_eq!((report.inserted, report.replaced, report.errors.len()), (2, 1, 0));
+        assert_eq!(out, expected);
+        let (_, report) = QuakeMlAnnotator::new().mode(QuakeMlMode::Verify).annotate_str(&out).unwrap();
+        assert_eq!(report.errors.len(), 0);
+    }
+    #[test]
+    fn malformed() {
+        let fe = QuakeMlAnnotator::new();
+        let err = fe.annotate_str("<q:quakeml>\n<event>\n</q:quakeml>").unwrap_err();
+        assert_eq!(err.to_string(), "line 3: unexpected end tag </q:quakeml>");
+        let err = fe.annotate_str("<q:quakeml>\n<event/>").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: <q:quakeml> is not closed");
+        let err = fe.annotate_str("<quakeml><!-- </quakeml>").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: unterminated comment");
+        let err = fe.annotate_str("<kml></kml>").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: not a QuakeML document");
+        assert_eq!(unescape("a &amp;&#65;&#x42; &bogus; &"), "a &AB &bogus; &");
+    }
+}
@@ -0,0 +1,350 @@
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::io::Read;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use crate::{Lookup, RegionError};
+#[cfg(feature = "std")]
+use crate::data::{self, DataError};
+use crate::tiers::Tiers;
+
+/// Error loading a regionalization
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum LoadError {
+    /// A data file could not be read
+    Io {
+        /// File name
+        file: String,
+        /// Underlying error
+        error: std::io::Error,
+    },
+    /// A data file is malformed or inconsistent with the others
+    Data(DataError),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { file, error } => write!(f, "reading {}: {}", file, error),
+            LoadError::Data(err) => write!(f, "invalid data file {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io { error, .. } => Some(error),
+            LoadError::Data(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<DataError> for LoadError {
+    fn from(err: DataError) -> Self {
+        LoadError::Data(err)
+    }
+}
+
+/// Table built into the crate or loaded at run time
+#[derive(Debug,Clone)]
+enum Table<T: 'static> {
+    Static(&'static [T]),
+    #[cfg(feature = "std")]
+    Owned(Vec<T>),
+}
+
+impl<T> core::ops::Deref for Table<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        match self {
+            Table::Static(t) => t,
+            #[cfg(feature = "std")]
+            Table::Owned(t) => t,
+        }
+    }
+}
+
+#[derive(Debug,Clone)]
+enum Names {
+    Static(&'static [&'static str]),
+    #[cfg(feature = "std")]
+    Owned(Vec<String>),
+}
+
+/// A geographic regionalization: latitude tiers and region names
+///
+/// The built-in 1995 tables are available as [`Regionalization::fe1995`]
+/// and are the ones used by [`Lookup`] and the free functions;
+/// modified tables in the same layout as `data/` can be loaded at run time
+/// with [`from_dir`](Regionalization::from_dir) or
+/// [`from_readers`](Regionalization::from_readers) and looked up with
+/// [`Lookup::region_number_in`].
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let fe95 = fe::Regionalization::fe1995();
+///  assert_eq!(fe95.region(41.440971, -71.502289).unwrap(), "SOUTHERN NEW ENGLAND");
+/// ```
+///
+#[derive(Debug,Clone)]
+pub struct Regionalization {
+    onsets: Table<u16>,
+    lons: Table<u8>,
+    regions: Table<u32>,
+    names: Names,
+    /// Dense grid of region numbers, only for the built-in tables
+    #[cfg(feature = "dense-grid")]
+    grid: Option<&'static [u16]>,
+}
+
+static FE1995: Regionalization = Regionalization {
+    onsets: Table::Static(crate::tiers::TIERS_1995.onsets),
+    lons: Table::Static(crate::tiers::TIERS_1995.lons),
+    regions: Table::Static(crate::tiers::TIERS_1995.regions),
+    names: Names::Static(&crate::NAMES),
+    #[cfg(feature = "dense-grid")]
+    grid: Some(&crate::dense_grid::GRID_1995),
+};
+
+/// Read all of a data file
+#[cfg(feature = "std")]
+fn read_all<R: Read>(file: &str, mut src: R) -> Result<String, LoadError> {
+    let mut out = String::new();
+    src.read_to_string(&mut out)
+        .map_err(|error| LoadError::Io { file: file.to_string(), error })?;
+    Ok(out)
+}
+
+impl Regionalization {
+    /// The built-in 1995 revision (Young et al., 1996)
+    pub fn fe1995() -> &'static Regionalization {
+        &FE1995
+    }
+    /// Load from a directory containing quadsidx.asc, nesect.asc,
+    /// nwsect.asc, sesect.asc, swsect.asc and names.asc
+    ///
+    /// ```rust
+    ///  use flinn_engdahl as fe;
+    ///  let local = fe::Regionalization::from_dir("data").unwrap();
+    ///  assert_eq!(local.region_number(41.440971, -71.502289), Ok(476));
+    ///
+    ///  let wrap = fe::Lookup::new().longitude(fe::LongitudePolicy::Wrap);
+    ///  assert_eq!(wrap.region_number_in(&local, 41.440971, 288.497711 + 360.0), Ok(476));
+    ///
+    ///  let err = fe::Regionalization::from_dir("no/such/dir").unwrap_err();
+    ///  assert!(err.to_string().starts_with("reading no/such/dir/quadsidx.asc"));
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<Self, LoadError> {
+        let dir = dir.as_ref();
+        let open = |file: &str| {
+            let path = dir.join(file);
+            std::fs::File::open(&path)
+                .map_err(|error| LoadError::Io { file: path.display().to_string(), error })
+        };
+        let quadsidx = open("quadsidx.asc")?;
+        let sects = [open(data::SECTS[0])?, open(data::SECTS[1])?,
+                     open(data::SECTS[2])?, open(data::SECTS[3])?];
+        Self::from_readers(quadsidx, sects, open("names.asc")?)
+    }
+    /// Load from the contents of quadsidx.asc, the four quadrant section
+    /// files (NE, NW, SE, SW) and names.asc
+    #[cfg(feature = "std")]
+    pub fn from_readers<R: Read>(quadsidx: R, sects: [R; 4], names: R) -> Result<Self, LoadError> {
+        let names = data::parse_names("names.asc", &read_all("names.asc", names)?)?;
+        let llindx = data::parse_quadsidx("quadsidx.asc", &read_all("quadsidx.asc", quadsidx)?)?;
+        let mut parsed = vec![];
+        for (file, src) in data::SECTS.iter().zip(sects) {
+            parsed.push((*file, data::parse_sect(file, &read_all(file, src)?)?));
+        }
+        let lattiers = data::latitude_tiers(&llindx, &parsed, names.len())?;
+        let tiers = data::compact(&llindx, &lattiers)?;
+        Ok(Regionalization {
+            onsets: Table::Owned(tiers.onsets),
+            lons: Table::Owned(tiers.lons),
+            regions: Table::Owned(tiers.regions),
+            names: Names::Owned(names),
+            #[cfg(feature = "dense-grid")]
+            grid: None,
+        })
+    }
+    pub(crate) fn tiers(&self) -> Tiers<'_> {
+        Tiers { onsets: &self.onsets, lons: &self.lons, regions: &self.regions }
+    }
+    /// Iterate over every 1 x 1 degree cell as (south latitude, west longitude, region number)
+    ///
+    /// Cells are walked directly from the latitude tiers, quadrant by quadrant
+    /// (NE, NW, SE, SW), tier by tier away from the equator and segment by
+    /// segment away from the prime meridian.  The degenerate tiers at the
+    /// poles and at longitude 180 are not included, giving 4 x 90 x 180 cells.
+    ///
+    #[cfg(feature = "std")]
+    pub(crate) fn tier_cells(&self) -> impl Iterator<Item = (i16, i16, usize)> + '_ {
+        let tiers = self.tiers();
+        let quadids = crate::quadids();
+        // Quadrant order follows the files: NE, NW, SE, SW
+        let quads = [(quadids[3], 1, 1), (quadids[2], 1, -1),
+                     (quadids[1], -1, 1), (quadids[0], -1, -1)];
+        (0 .. 4).flat_map(move |q| {
+            let (quadon, slat, slon) = quads[q];
+            (0 .. 90).flat_map(move |lt| {
+                let (first, last) = tiers.segments(quadon + lt - 1);
+                (first .. last).flat_map(move |i| {
+                    let start = tiers.lon(i);
+                    let end = if i + 1 < last { tiers.lon(i+1) } else { 180 };
+                    (start .. end.min(180)).map(move |ln| {
+                        let lat = if slat > 0 { lt as i16 } else { -(lt as i16) - 1 };
+                        let lon = if slon > 0 { ln as i16 } else { -(ln as i16) - 1 };
+                        (lat, lon, tiers.region(i))
+                    })
+                })
+            })
+        })
+    }
+    /// Dense grid of region numbers, if precomputed
+    #[cfg(feature = "dense-grid")]
+    pub(crate) fn grid(&self) -> Option<&'static [u16]> {
+        self.grid
+    }
+    /// Number of geographic regions
+    pub fn number_of_regions(&self) -> usize {
+        match &self.names {
+            Names::Static(names) => names.len(),
+            #[cfg(feature = "std")]
+            Names::Owned(names) => names.len(),
+        }
+    }
+    /// Name of geographic region `n`, 1-based
+    pub fn name(&self, n: usize) -> Option<&str> {
+        if n == 0 || n > self.number_of_regions() {
+            return None;
+        }
+        match &self.names {
+            Names::Static(names) => Some(names[n-1]),
+            #[cfg(feature = "std")]
+            Names::Owned(names) => Some(&names[n-1]),
+        }
+    }
+    /// Get the region number from a location at (`lat`,`lon`)
+    ///
+    /// Uses the default [`Lookup`]; for other longitude conventions see
+    /// [`Lookup::region_number_in`]
+    ///
+    /// # Arguments
+    ///   - lat - Latitude  [-90, 90]
+    ///   - lon - Longitude [-360, 360]
+    ///
+    pub fn region_number(&self, lat: f64, lon: f64) -> Result<usize, RegionError> {
+        Lookup::new().region_number_in(self, lat, lon)
+    }
+    /// Get the region name from a location at (`lat`,`lon`)
+    ///
+    /// Uses the default [`Lookup`]; for other longitude conventions see
+    /// [`Lookup::region_in`]
+    ///
+    /// # Arguments
+    ///   - lat - Latitude  [-90, 90]
+    ///   - lon - Longitude [-360, 360]
+    ///
+    pub fn region(&self, lat: f64, lon: f64) -> Result<&str, RegionError> {
+        Lookup::new().region_in(self, lat, lon)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn read(file: &str) -> Vec<u8> {
+        std::fs::read(Path::new("data").join(file)).unwrap()
+    }
+    fn sects() -> [Vec<u8>; 4] {
+        [read("nesect.asc"), read("nwsect.asc"), read("sesect.asc"), read("swsect.asc")]
+    }
+
+    #[test]
+    fn loaded_matches_builtin() {
+        let fe = Regionalization::from_dir("data").unwrap();
+        assert_eq!(fe.number_of_regions(), 757);
+        for lat in -90 ..= 90 {
+            for lon in -180 ..= 180 {
+                let (lat, lon) = (lat as f64 * 0.99, lon as f64 * 0.99);
+                assert_eq!(fe.region_number(lat, lon), crate::region_number(lat, lon));
+                assert_eq!(fe.region(lat, lon), Regionalization::fe1995().region(lat, lon));
+            }
+        }
+        assert_eq!(fe.region_number(91., 0.), Err(RegionError::BadLatitude));
+    }
+    #[test]
+    fn builtin_names() {
+        let fe = Regionalization::fe1995();
+        assert_eq!(fe.number_of_regions(), 757);
+        assert_eq!(fe.name(0), None);
+        assert_eq!(fe.name(1), Some("CENTRAL ALASKA"));
+        assert_eq!(fe.name(758), None);
+    }
+    #[test]
+    fn tier_cells_match_lookup() {
+        let mut n = 0;
+        for (lat, lon, rid) in Regionalization::fe1995().tier_cells() {
+            let (lat, lon) = (lat as f64 + 0.5, lon as f64 + 0.5);
+            assert_eq!(crate::region_number(lat, lon), Ok(rid), "{} {}", lat, lon);
+            n += 1;
+        }
+        assert_eq!(n, 360 * 180);
+    }
+    #[test]
+    fn lookup_settings() {
+        use crate::{Antimeridian, Backend, LongitudePolicy};
+        let fe = Regionalization::from_dir("data").unwrap();
+        let wrap = Lookup::new().longitude(LongitudePolicy::Wrap);
+        assert_eq!(fe.region_number(0., 370.5), Err(RegionError::BadLongitude));
+        assert_eq!(wrap.region_number_in(&fe, 0., 370.5), crate::region_number(0., 10.5));
+        assert_eq!(wrap.region_in(&fe, -42.448299, 171.214005 + 720.0), Ok("SOUTH ISLAND, NEW ZEALAND"));
+        let west = Lookup::new().antimeridian(Antimeridian::West);
+        for &lat in [-60.5, -15.5, 0.0, 51.5, 65.5].iter() {
+            assert_eq!(west.region_number_in(&fe, lat, 180.), crate::region_number(lat, -180.));
+            assert_eq!(west.region_number_in(&fe, lat, 180.), west.region_number(lat, 180.));
+        }
+        let scan = Lookup::new().backend(Backend::TierScan);
+        let builtin = Regionalization::fe1995();
+        assert_eq!(scan.region_number_in(builtin, 0.5, 0.5), Lookup::new().region_number_in(builtin, 0.5, 0.5));
+    }
+    #[test]
+    fn modified_tables() {
+        // Rename region 476 and move the first NE segment to it
+        let mut names = String::from_utf8(read("names.asc")).unwrap();
+        names = names.replace("SOUTHERN NEW ENGLAND", "OUR NETWORK");
+        let mut sects = sects();
+        let ne = String::from_utf8(sects[0].clone()).unwrap().replacen("0 561", "0 476", 1);
+        sects[0] = ne.into_bytes();
+        let [a, b, c, d] = sects;
+        let fe = Regionalization::from_readers(&read("quadsidx.asc")[..], [&a[..], &b[..], &c[..], &d[..]],
+                                               names.as_bytes()).unwrap();
+        assert_eq!(fe.region(0.5, 0.5), Ok("OUR NETWORK"));
+        assert_eq!(crate::region_number(0.5, 0.5), Ok(561));
+    }
+    #[test]
+    fn load_errors() {
+        let [a, b, c, d] = sects();
+        let sects = [&a[..], &b[..], &c[..], &d[..]];
+        let err = Regionalization::from_readers(&b"1 2 3"[..], sects, &read("names.asc")[..]).unwrap_err();
+        assert_eq!(err.to_string(), "invalid data file quadsidx.asc: 3 tiers is not a multiple of 4 quadrants");
+        let err = Regionalization::from_readers(&read("quadsidx.asc")[..], sects, &b"A\nB\n"[..]).unwrap_err();
+        match err {
+            LoadError::Data(e) => assert_eq!(e.file, "nesect.asc"),
+            _ => panic!("{}", err),
+        }
+        let err = Regionalization::from_readers(&read("quadsidx.asc")[..], [&a[..], &b[..], &c[..], &[0xff][..]],
+                                                &read("names.asc")[..]).unwrap_err();
+        assert!(matches!(err, LoadError::Io { ref file, .. } if file == "swsect.asc"));
+    }
+}
@@ -0,0 +1,310 @@
+//! Event region, `ievreg`, of SAC binary files
+//!
+//! Only the fixed 632 byte header is read.  It is kept as raw bytes, so a
+//! header written back differs from the one read only in the fields set;
+//! the data section of a file is never touched.
+
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::{Lookup, RegionError};
+
+/// Value of undefined integer and enumerated header fields
+pub const SAC_UNDEFINED: i32 = -12345;
+
+/// Word offsets of header fields
+const EVLA: usize = 35;
+const EVLO: usize = 36;
+const NVHDR: usize = 76;
+const IEVREG: usize = 91;
+
+/// Byte order of a SAC file
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum ByteOrder {
+    /// Least significant byte first
+    Little,
+    /// Most significant byte first
+    Big,
+}
+
+/// Error reading a SAC header or finding its event region
+#[derive(Debug)]
+pub enum SacError {
+    /// Reading or writing the file failed
+    Io(io::Error),
+    /// The header is too short or its version is not recognized in either byte order
+    NotSac,
+    /// `evla` or `evlo` is undefined
+    NoEventLocation,
+    /// The event location is out of range
+    Region(RegionError),
+}
+
+impl fmt::Display for SacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SacError::Io(err) => write!(f, "{}", err),
+            SacError::NotSac => write!(f, "not a SAC file"),
+            SacError::NoEventLocation => write!(f, "event location evla, evlo undefined"),
+            SacError::Region(err) => write!(f, "event location: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SacError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SacError::Io(err) => Some(err),
+            SacError::Region(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SacError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::UnexpectedEof => SacError::NotSac,
+            _ => SacError::Io(err),
+        }
+    }
+}
+
+/// Stored and computed event region of a SAC header
+#[derive(Debug,Copy,Clone,PartialEq,Eq)]
+pub struct IevregCheck {
+    /// `ievreg` in the header, None if undefined
+    pub stored: Option<i32>,
+    /// Region number from `evla` and `evlo`
+    pub computed: i32,
+}
+
+impl IevregCheck {
+    /// Whether the stored region is the computed one
+    pub fn matches(&self) -> bool {
+        self.stored == Some(self.computed)
+    }
+}
+
+/// Binary header of a SAC file, in either byte order
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let mut bytes = vec![0u8; fe::SacHeader::LEN];
+///  bytes[304..308].copy_from_slice(&6i32.to_be_bytes());        // nvhdr
+///  bytes[140..144].copy_from_slice(&41.44f32.to_be_bytes());    // evla
+///  bytes[144..148].copy_from_slice(&(-71.5f32).to_be_bytes());  // evlo
+///  bytes[364..368].copy_from_slice(&(-12345i32).to_be_bytes()); // ievreg
+///
+///  let mut sac = fe::SacHeader::from_bytes(&bytes).unwrap();
+///  assert_eq!(sac.byte_order(), fe::ByteOrder::Big);
+///  assert_eq!(sac.ievreg(), None);
+///  let check = sac.fill_ievreg().unwrap();
+///  assert_eq!((check.stored, check.computed), (None, 476));
+///  assert_eq!(sac.ievreg(), Some(476));
+///  assert!(sac.check_ievreg().unwrap().matches());
+/// ```
+///
+#[derive(Clone)]
+pub struct SacHeader {
+    bytes: [u8; SacHeader::LEN],
+    order: ByteOrder,
+}
+
+impl fmt::Debug for SacHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SacHeader")
+            .field("order", &self.order)
+            .field("evla", &self.evla())
+            .field("evlo", &self.evlo())
+            .field("ievreg", &self.ievreg())
+            .finish()
+    }
+}
+
+impl SacHeader {
+    /// Length of the binary header in bytes
+    pub const LEN: usize = 632;
+
+    /// Header from the first [`LEN`](Self::LEN) bytes of a SAC file
+    ///
+    /// The byte order is the one in which the header version, `nvhdr`, is
+    /// between 1 and 20
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SacError> {
+        if bytes.len() < Self::LEN {
+            return Err(SacError::NotSac);
+        }
+        let mut header = SacHeader { bytes: [0; Self::LEN], order: ByteOrder::Little };
+        header.bytes.copy_from_slice(&bytes[.. Self::LEN]);
+        for &order in &[ByteOrder::Little, ByteOrder::Big] {
+            header.order = order;
+            if (1 ..= 20).contains(&header.int(NVHDR)) {
+                return Ok(header);
+            }
+        }
+        Err(SacError::NotSac)
+    }
+    /// Read the header from the start of a SAC file
+    pub fn read<R: Read>(mut src: R) -> Result<Self, SacError> {
+        let mut bytes = [0; Self::LEN];
+        src.read_exact(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+    /// Write the header, in its original byte order
+    pub fn write<W: Write>(&self, mut dst: W) -> io::Result<()> {
+        dst.write_all(&self.bytes)
+    }
+    /// Raw header bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    /// Byte order of the header
+    pub fn byte_order(&self) -> ByteOrder {
+        self.order
+    }
+    fn word(&self, w: usize) -> [u8; 4] {
+        let mut b = [0; 4];
+        b.copy_from_slice(&self.bytes[4 * w .. 4 * w + 4]);
+        b
+    }
+    fn int(&self, w: usize) -> i32 {
+        match self.order {
+            ByteOrder::Little => i32::from_le_bytes(self.word(w)),
+            ByteOrder::Big => i32::from_be_bytes(self.word(w)),
+        }
+    }
+    fn set_int(&mut self, w: usize, v: i32) {
+        let b = match self.order {
+            ByteOrder::Little => v.to_le_bytes(),
+            ByteOrder::Big => v.to_be_bytes(),
+        };
+        self.bytes[4 * w .. 4 * w + 4].copy_from_slice(&b);
+    }
+    fn float(&self, w: usize) -> Option<f32> {
+        let v = match self.order {
+            ByteOrder::Little => f32::from_le_bytes(self.word(w)),
+            ByteOrder::Big => f32::from_be_bytes(self.word(w)),
+        };
+        Some(v).filter(|&v| v != SAC_UNDEFINED as f32)
+    }
+    /// Event latitude, None if undefined
+    pub fn evla(&self) -> Option<f32> {
+        self.float(EVLA)
+    }
+    /// Event longitude, None if undefined
+    pub fn evlo(&self) -> Option<f32> {
+        self.float(EVLO)
+    }
+    /// Event region, None if undefined
+    pub fn ievreg(&self) -> Option<i32> {
+        Some(self.int(IEVREG)).filter(|&v| v != SAC_UNDEFINED)
+    }
+    /// Set the event region, None for undefined
+    pub fn set_ievreg(&mut self, region: Option<i32>) {
+        self.set_int(IEVREG, region.unwrap_or(SAC_UNDEFINED));
+    }
+    /// Compare `ievreg` with the region of `evla`, `evlo` found by `lookup`
+    pub fn check_ievreg_with(&self, lookup: &Lookup) -> Result<IevregCheck, SacError> {
+        let (lat, lon) = match (self.evla(), self.evlo()) {
+            (Some(lat), Some(lon)) => (lat as f64, lon as f64),
+            _ => return Err(SacError::NoEventLocation),
+        };
+        let n = lookup.region_number(lat, lon).map_err(SacError::Region)?;
+        Ok(IevregCheck { stored: self.ievreg(), computed: n as i32 })
+    }
+    /// Compare `ievreg` with the region of `evla`, `evlo`
+    pub fn check_ievreg(&self) -> Result<IevregCheck, SacError> {
+        self.check_ievreg_with(&Lookup::new())
+    }
+    /// Set `ievreg` to the region of `evla`, `evlo`, returning the previous and new values
+    pub fn fill_ievreg(&mut self) -> Result<IevregCheck, SacError> {
+        let check = self.check_ievreg()?;
+        self.set_ievreg(Some(check.computed));
+        Ok(check)
+    }
+}
+
+/// Compare the stored `ievreg` of a SAC file with the region of its event location
+pub fn sac_check_ievreg<P: AsRef<Path>>(path: P) -> Result<IevregCheck, SacError> {
+    SacHeader::read(std::fs::File::open(path)?)?.check_ievreg()
+}
+
+/// Set `ievreg` of a SAC file to the region of its event location
+///
+/// The header is rewritten in place only if `ievreg` changes; the
+/// returned check holds the previous and new values
+pub fn sac_fill_ievreg<P: AsRef<Path>>(path: P) -> Result<IevregCheck, SacError> {
+    let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+    let mut header = SacHeader::read(&mut file)?;
+    let check = header.fill_ievreg()?;
+    if !check.matches() {
+        file.seek(SeekFrom::Start(0))?;
+        header.write(&mut file)?;
+    }
+    Ok(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(order: ByteOrder, evla: f32, evlo: f32) -> Vec<u8> {
+        let int = |v: i32| if order == ByteOrder::Little { v.to_le_bytes() } else { v.to_be_bytes() };
+        let float = |v: f32| int(v.to_bits() as i32);
+        let mut out = vec![];
+        for w in 0 .. 110 {
+            out.extend_from_slice(&match w {
+                EVLA => float(evla),
+                EVLO => float(evlo),
+                NVHDR => int(6),
+                w if w < 70 => float(-12345.0),
+                _ => int(SAC_UNDEFINED),
+            });
+        }
+        out.extend(b"STA     ".iter().cycle().take(SacHeader::LEN - out.len()));
+        out
+    }
+
+    #[test]
+    fn byte_orders() {
+        for &order in &[ByteOrder::Little, ByteOrder::Big] {
+            let bytes = header(order, -42.45, 171.21);
+            let mut sac = SacHeader::read(&bytes[..]).unwrap();
+            assert_eq!(sac.byte_order(), order);
+            assert_eq!((sac.evla(), sac.ievreg()), (Some(-42.45), None));
+            assert_eq!(sac.fill_ievreg().unwrap(), IevregCheck { stored: None, computed: 162 });
+            let mut out = vec![];
+            sac.write(&mut out).unwrap();
+            let changed : Vec<_> = (0 .. out.len()).filter(|&i| out[i] != bytes[i]).collect();
+            assert!(changed.iter().all(|i| (4 * IEVREG .. 4 * IEVREG + 4).contains(i)), "{:?}", changed);
+            assert_eq!(SacHeader::from_bytes(&out).unwrap().ievreg(), Some(162));
+            sac.set_ievreg(Some(12));
+            assert_eq!(sac.check_ievreg().unwrap(), IevregCheck { stored: Some(12), computed: 162 });
+        }
+    }
+    #[test]
+    fn errors() {
+        assert!(matches!(SacHeader::read(&[0u8; 100][..]), Err(SacError::NotSac)));
+        assert!(matches!(SacHeader::from_bytes(&[0u8; 632]), Err(SacError::NotSac)));
+        let sac = SacHeader::from_bytes(&header(ByteOrder::Big, -12345.0, 10.0)).unwrap();
+        assert!(matches!(sac.check_ievreg(), Err(SacError::NoEventLocation)));
+        let sac = SacHeader::from_bytes(&header(ByteOrder::Little, 95.0, 10.0)).unwrap();
+        assert_eq!(sac.check_ievreg().unwrap_err().to_string(), "event location: latitude out of range [-90, 90]");
+    }
+    #[test]
+    fn files() {
+        let path = std::env::temp_dir().join(format!("flinn_engdahl_{}.sac", std::process::id()));
+        let mut bytes = header(ByteOrder::Little, 41.44, -71.5);
+        bytes.extend_from_slice(&[1, 2, 3, 4]);
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(!sac_check_ievreg(&path).unwrap().matches());
+        assert_eq!(sac_fill_ievreg(&path).unwrap(), IevregCheck { stored: None, computed: 476 });
+        assert_eq!(sac_check_ievreg(&path).unwrap(), IevregCheck { stored: Some(476), computed: 476 });
+        let after = std::fs::read(&path).unwrap();
+        assert_eq!((after.len(), &after[632 ..]), (bytes.len(), &[1u8, 2, 3, 4][..]));
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(sac_check_ievreg(&path), Err(SacError::Io(_))));
+    }
+}
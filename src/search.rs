@@ -0,0 +1,214 @@
+use crate::Regionalization;
+
+/// Candidate region from a name search
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct SearchMatch {
+    /// Geographic Region Number
+    pub region: usize,
+    /// Score in (0, 1], 1 is an exact match of every token
+    pub score: f64,
+}
+
+/// Abbreviations spanning several characters or words, replaced before splitting
+const PHRASES: [(&str, &str); 6] = [
+    ("N.Z.", " NEW ZEALAND "),
+    ("P.N.G.", " PAPUA NEW GUINEA "),
+    ("PNG.", " PAPUA NEW GUINEA "),
+    ("S.E.", " SOUTHEAST "),
+    ("N.E.", " NORTHEAST "),
+    ("'", ""),
+];
+
+/// Canonical form of single abbreviated or inflected tokens
+const TOKENS: [(&str, &str); 33] = [
+    ("REG", "REGION"),
+    ("IS", "ISLAND"),
+    ("ISL", "ISLAND"),
+    ("ISLANDS", "ISLAND"),
+    ("BORD", "BORDER"),
+    ("BRD", "BORDER"),
+    ("CALIF", "CALIFORNIA"),
+    ("PROV", "PROVINCE"),
+    ("ARG", "ARGENTINA"),
+    ("ST", "SAINT"),
+    ("PNG", "PAPUA NEW GUINEA"),
+    ("NZ", "NEW ZEALAND"),
+    ("N", "NORTH"),
+    ("NORTHERN", "NORTH"),
+    ("S", "SOUTH"),
+    ("SOUTHERN", "SOUTH"),
+    ("E", "EAST"),
+    ("EASTERN", "EAST"),
+    ("W", "WEST"),
+    ("WESTERN", "WEST"),
+    ("C", "CENTRAL"),
+    ("NE", "NORTHEAST"),
+    ("NORTHEASTERN", "NORTHEAST"),
+    ("NW", "NORTHWEST"),
+    ("NORTHWESTERN", "NORTHWEST"),
+    ("SE", "SOUTHEAST"),
+    ("SOUTHEASTERN", "SOUTHEAST"),
+    ("SW", "SOUTHWEST"),
+    ("SOUTHWESTERN", "SOUTHWEST"),
+    ("MTS", "MOUNTAIN"),
+    ("MOUNTAINS", "MOUNTAIN"),
+    ("USA", "UNITED STATES"),
+    ("US", "UNITED STATES"),
+];
+
+/// Tokens carrying no information
+const STOP: [&str; 3] = ["OF", "THE", "AND"];
+
+/// Split a name or query into canonical upper case tokens
+fn tokens(s: &str) -> Vec<String> {
+    let mut s = s.to_uppercase();
+    for (from, to) in PHRASES.iter() {
+        s = s.replace(from, to);
+    }
+    let mut out = vec![];
+    for t in s.split(|c: char| !c.is_alphanumeric()).filter(|t| !t.is_empty()) {
+        if STOP.contains(&t) {
+            continue;
+        }
+        let t = TOKENS.iter().find(|(from, _)| *from == t).map(|(_, to)| *to).unwrap_or(t);
+        out.extend(t.split(' ').map(|t| t.to_string()));
+    }
+    out
+}
+
+/// Levenshtein distance between two tokens
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut prev : Vec<usize> = (0 ..= b.len()).collect();
+    for i in 1 ..= a.len() {
+        let mut cur = vec![i; b.len() + 1];
+        for j in 1 ..= b.len() {
+            let sub = prev[j-1] + (a[i-1] != b[j-1]) as usize;
+            cur[j] = sub.min(prev[j] + 1).min(cur[j-1] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Similarity of a query token to a name token in [0, 1]
+fn similarity(q: &str, t: &str) -> f64 {
+    if q == t {
+        return 1.0;
+    }
+    if q.len() >= 2 && t.starts_with(q) {
+        return 0.5 + 0.4 * q.len() as f64 / t.len() as f64;
+    }
+    match edit_distance(q, t) {
+        1 if q.len() >= 4 => 0.7,
+        2 if q.len() >= 7 => 0.5,
+        _ => 0.0,
+    }
+}
+
+/// Score a tokenized query against a tokenized name
+fn score(query: &[String], name: &[String]) -> f64 {
+    let mut used = vec![false; name.len()];
+    let mut total = 0.0;
+    for q in query {
+        let best = name.iter().enumerate()
+            .filter(|(j, _)| !used[*j])
+            .map(|(j, t)| (j, similarity(q, t)))
+            .fold((0, 0.0), |a, b| if b.1 > a.1 { b } else { a });
+        if best.1 > 0.0 {
+            used[best.0] = true;
+            total += best.1;
+        }
+    }
+    let matched = used.iter().filter(|&&u| u).count();
+    // Prefer names with fewer unmatched tokens
+    total / query.len() as f64 * (0.8 + 0.2 * matched as f64 / name.len().max(1) as f64)
+}
+
+impl Regionalization {
+    /// Search region names, see [`search_regions`](crate::search_regions)
+    pub fn search_regions(&self, query: &str) -> Vec<SearchMatch> {
+        let query = tokens(query);
+        if query.is_empty() {
+            return vec![];
+        }
+        let mut out : Vec<_> = (1 ..= self.number_of_regions())
+            .map(|region| {
+                let name = tokens(self.name(region).unwrap());
+                SearchMatch { region, score: score(&query, &name) }
+            })
+            .filter(|m| m.score > 0.0)
+            .collect();
+        out.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap().then(a.region.cmp(&b.region)));
+        out
+    }
+}
+
+/// Search region names for a partial or abbreviated name
+///
+/// Matching is case-insensitive and token based.  Abbreviations used in the
+/// region names ("IS.", "REG.", "BORD", "CALIF.", "N.Z.", ...) and directions
+/// ("N.", "NORTHERN", ...) are expanded before comparison, and tokens may
+/// match by prefix or with small misspellings.
+///
+/// ```rust
+///  use flinn_engdahl as fe;
+///  let found = fe::search_regions("andreanof islands");
+///  assert_eq!(found[0].region, 7); // ANDREANOF ISLANDS, ALEUTIAN IS.
+///  assert_eq!(found[0].score, 0.9);
+/// ```
+///
+/// # Arguments
+///   - query - Partial region name
+///
+/// # Returns
+///   - Matching regions with scores, best match first
+///
+pub fn search_regions(query: &str) -> Vec<SearchMatch> {
+    Regionalization::fe1995().search_regions(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn best(q: &str) -> usize {
+        search_regions(q)[0].region
+    }
+
+    #[test]
+    fn tokenize() {
+        assert_eq!(tokens("ANDREANOF ISLANDS, ALEUTIAN IS."), ["ANDREANOF", "ISLAND", "ALEUTIAN", "ISLAND"]);
+        assert_eq!(tokens("AFGHANISTAN-TAJIKISTAN BORD REG."), ["AFGHANISTAN", "TAJIKISTAN", "BORDER", "REGION"]);
+        assert_eq!(tokens("OFF W. COAST OF N. ISLAND, N.Z."), ["OFF", "WEST", "COAST", "NORTH", "ISLAND", "NEW", "ZEALAND"]);
+        assert_eq!(tokens("  "), Vec::<String>::new());
+    }
+    #[test]
+    fn exact_names() {
+        for r in (1 ..= 757).step_by(11) {
+            let m = &search_regions(crate::NAMES[r-1])[0];
+            assert_eq!(m.score, 1.0, "{}", crate::NAMES[r-1]);
+        }
+    }
+    #[test]
+    fn partial_names() {
+        let aleutian = search_regions("aleutian");
+        assert_eq!(aleutian.len(), 5);
+        for m in &aleutian {
+            assert!(crate::NAMES[m.region-1].contains("ALEUTIAN"));
+        }
+        assert_eq!(best("south island new zealand"), 162);
+        assert_eq!(best("afghanistan tajikistan border region"), 717);
+        assert_eq!(best("near coast northern california"), 35);
+        assert_eq!(best("Kermadec Islands"), 177);
+        assert_eq!(best("hindu kush"), 718);
+        assert!(crate::NAMES[best("Philipines")-1].contains("PHILIPPINE"));
+        assert_eq!(best("southern new england"), 476);
+        for m in &search_regions("new zealand")[..5] {
+            let name = crate::NAMES[m.region-1];
+            assert!(name.contains("NEW ZEALAND") || name.contains("N.Z."), "{}", name);
+        }
+        assert!(search_regions("").is_empty());
+        assert!(search_regions("qqqqqq").is_empty());
+    }
+}
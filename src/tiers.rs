@@ -0,0 +1,113 @@
+//! Latitude tiers in compact form
+//!
+//! Each tier is a run of segments, a segment being the longitude (truncated)
+//! at which it starts and its region number.  The tiers are stored as the
+//! index of the first segment of each tier, the segment longitudes as `u8`
+//! and the region numbers as 10 bit values packed three to a `u32`, about
+//! 14 KB for the 1995 revision.  The tables are generated by the build
+//! script from the quadrant index and section files in `data/`.
+
+/// Bits per packed region number
+const BITS: usize = 10;
+/// Packed region numbers per word
+const PER_WORD: usize = 3;
+
+/// Latitude tiers of a revision
+#[derive(Debug,Copy,Clone)]
+pub(crate) struct Tiers<'a> {
+    /// Index of the first segment of each tier, followed by the number of segments
+    pub onsets: &'a [u16],
+    /// Longitude (truncated) at which each segment starts
+    pub lons: &'a [u8],
+    /// Region number of each segment, packed
+    pub regions: &'a [u32],
+}
+
+impl Tiers<'_> {
+    /// Range of segments in tier `rec`
+    pub const fn segments(&self, rec: usize) -> (usize, usize) {
+        (self.onsets[rec] as usize, self.onsets[rec + 1] as usize)
+    }
+    /// Longitude at which segment `i` starts
+    #[cfg_attr(not(any(feature = "std", feature = "dense-grid")), allow(dead_code))]
+    pub const fn lon(&self, i: usize) -> usize {
+        self.lons[i] as usize
+    }
+    /// Region number of segment `i`
+    pub const fn region(&self, i: usize) -> usize {
+        let word = self.regions[i / PER_WORD];
+        ((word >> (BITS * (i % PER_WORD))) & ((1 << BITS) - 1)) as usize
+    }
+    /// Region number at truncated longitude `ln` in tier `rec`
+    ///
+    /// The segment covering `ln` is the last one starting at or before it
+    pub fn lookup(&self, rec: usize, ln: usize) -> usize {
+        let (start, end) = self.segments(rec);
+        let k = self.lons[start .. end].partition_point(|&lon| lon as usize <= ln);
+        self.region(start + k - 1)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/latitude_tiers.rs"));
+include!(concat!(env!("OUT_DIR"), "/lat_lon_index.rs"));
+
+/// Latitude tiers of the 1995 revision
+pub(crate) static TIERS_1995: Tiers<'static> = Tiers {
+    onsets: &TIER_ONSETS,
+    lons: &TIER_LONS,
+    regions: &TIER_REGIONS,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_size() {
+        let bytes = core::mem::size_of_val(&TIER_ONSETS)
+            + core::mem::size_of_val(&TIER_LONS)
+            + core::mem::size_of_val(&TIER_REGIONS);
+        assert!(bytes < 16 * 1024, "{}", bytes);
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn matches_data_files() {
+        let llindx = crate::llindx();
+        let lattiers = crate::latitude_tiers_read();
+        let t = &TIERS_1995;
+        assert_eq!(t.onsets.len(), llindx.len() + 1);
+        for (rec, &(tieron, nbrbdy)) in llindx.iter().enumerate() {
+            assert_eq!(t.segments(rec), (tieron - 1, tieron - 1 + nbrbdy));
+        }
+        assert_eq!(t.lons.len(), lattiers.len());
+        for (i, &(lon, region)) in lattiers.iter().enumerate() {
+            assert_eq!((t.lon(i), t.region(i)), (lon, region));
+        }
+        let regions : Vec<_> = lattiers.iter().map(|p| p.1).collect();
+        assert_eq!(crate::data::pack_regions(&regions), t.regions);
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn lookup_matches_tuple_scan() {
+        // Original segment search over (longitude, region) pairs
+        fn scan(llindx: &[(usize,usize)], lattiers: &[(usize,usize)], rec: usize, ln: usize) -> usize {
+            let (tieron, nbrbdy) = llindx[rec];
+            let mut pair = lattiers[tieron-1];
+            for i in tieron - 1 .. tieron - 1 + nbrbdy {
+                pair = lattiers[i];
+                if lattiers[i].0 > ln {
+                    pair = lattiers[i-1];
+                    break;
+                }
+            }
+            pair.1
+        }
+        let llindx = crate::llindx();
+        let lattiers = crate::latitude_tiers_read();
+        for rec in 0 .. llindx.len() {
+            for ln in 0 ..= 180 {
+                assert_eq!(TIERS_1995.lookup(rec, ln), scan(&llindx, &lattiers, rec, ln), "{} {}", rec, ln);
+            }
+        }
+    }
+}